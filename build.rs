@@ -0,0 +1,10 @@
+fn main() {
+    println!("cargo:rerun-if-changed=src/package.mjs");
+    println!("cargo:rerun-if-changed=esbuild.mjs");
+
+    let status = std::process::Command::new("npm")
+        .args(["run", "build:esbuild"])
+        .status()
+        .expect("Failed to run esbuild");
+    assert!(status.success(), "Failed to build src/bundle.js");
+}
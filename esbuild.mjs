@@ -1,9 +1,9 @@
 import * as esbuild from 'esbuild';
 
 await esbuild.build({
-    entryPoints: ['packages/app/src/package.mjs'],
+    entryPoints: ['src/package.mjs'],
     bundle: true,
-    outfile: 'packages/app/src/bundle.js',
+    outfile: 'src/bundle.js',
     minify: true,
     format: 'esm',
 });
\ No newline at end of file
@@ -30,6 +30,29 @@ impl Builds {
             .collect()
     }
 
+    pub async fn count_user_build(&self, user_id: &str) -> Result<usize> {
+        let r = self
+            .0
+            .list()
+            .prefix(format!("user_builds:user_id={}:id=", user_id))
+            .execute()
+            .await?;
+        Ok(r.keys.len())
+    }
+
+    pub async fn get_user_build(
+        &self,
+        user_id: &str,
+        id: &Uuid,
+    ) -> Result<Option<(BuildValue, BuildMetadata)>> {
+        let (value, metadata) = self
+            .0
+            .get(&user_build_key(user_id, id))
+            .json_with_metadata::<BuildValue, BuildMetadata>()
+            .await?;
+        Ok(value.zip(metadata))
+    }
+
     pub async fn put_user_build(
         &self,
         user_id: String,
@@ -37,14 +60,27 @@ impl Builds {
         metadata: BuildMetadata,
     ) -> Result<Uuid> {
         let uuid = Uuid::now_v7();
+        self.update_user_build(&user_id, &uuid, value, metadata)
+            .await?;
+        Ok(uuid)
+    }
+
+    pub async fn update_user_build(
+        &self,
+        user_id: &str,
+        id: &Uuid,
+        value: BuildValue,
+        metadata: BuildMetadata,
+    ) -> Result<()> {
         self.0
-            .put(
-                &format!("user_builds:user_id={}:id={}", user_id, uuid),
-                &value,
-            )?
+            .put(&user_build_key(user_id, id), &value)?
             .metadata(&metadata)?
             .execute()
             .await?;
-        Ok(uuid)
+        Ok(())
     }
 }
+
+fn user_build_key(user_id: &str, id: &Uuid) -> String {
+    format!("user_builds:user_id={}:id={}", user_id, id)
+}
@@ -1 +1,2 @@
 pub mod builds;
+pub mod rate_limits;
@@ -0,0 +1,105 @@
+#[cfg(test)]
+use std::cell::RefCell;
+#[cfg(test)]
+use std::collections::HashMap;
+
+use worker::kv::KvStore;
+use worker::*;
+
+use crate::rate_limit::{Decision, Limit, TokenBucket};
+
+/// KV has a minimum expiration TTL of 60 seconds.
+const MIN_TTL: u64 = 60;
+
+/// Where the token buckets are kept between requests.
+pub trait BucketStore {
+    async fn get(&self, name: &str) -> Result<Option<TokenBucket>>;
+    /// Stores `bucket`, which may be dropped after `ttl` seconds.
+    async fn put(&self, name: &str, bucket: &TokenBucket, ttl: u64) -> Result<()>;
+}
+
+/// Token buckets stored in KV.
+///
+/// KV is eventually consistent, so concurrent requests from different locations may
+/// each see a stale bucket. This is good enough to stop a single client from filling
+/// the store, which is all it is meant for.
+pub struct KvBuckets(pub KvStore);
+
+impl BucketStore for KvBuckets {
+    async fn get(&self, name: &str) -> Result<Option<TokenBucket>> {
+        Ok(self.0.get(name).json::<TokenBucket>().await?)
+    }
+
+    async fn put(&self, name: &str, bucket: &TokenBucket, ttl: u64) -> Result<()> {
+        self.0
+            .put(name, bucket)?
+            .expiration_ttl(ttl.max(MIN_TTL))
+            .execute()
+            .await?;
+        Ok(())
+    }
+}
+
+/// Token buckets kept in memory, standing in for KV in tests. Nothing expires.
+#[cfg(test)]
+#[derive(Default)]
+pub struct MemoryBuckets(pub RefCell<HashMap<String, TokenBucket>>);
+
+#[cfg(test)]
+impl BucketStore for MemoryBuckets {
+    async fn get(&self, name: &str) -> Result<Option<TokenBucket>> {
+        Ok(self.0.borrow().get(name).cloned())
+    }
+
+    async fn put(&self, name: &str, bucket: &TokenBucket, _ttl: u64) -> Result<()> {
+        self.0.borrow_mut().insert(name.to_string(), bucket.clone());
+        Ok(())
+    }
+}
+
+pub struct RateLimits<S>(S);
+
+impl<S: BucketStore> RateLimits<S> {
+    pub fn new(store: S) -> Self {
+        Self(store)
+    }
+
+    pub async fn take(&self, key: &str, limit: &Limit, now: u64) -> Result<Decision> {
+        let name = format!("rate_limit:{}", key);
+        let mut bucket = self
+            .0
+            .get(&name)
+            .await?
+            .unwrap_or_else(|| TokenBucket::full(limit, now));
+        let decision = bucket.take(limit, now);
+        self.0.put(&name, &bucket, limit.ttl()).await?;
+        Ok(decision)
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod test {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    const LIMIT: Limit = Limit {
+        capacity: 1.0,
+        refill_per_sec: 0.5,
+    };
+
+    #[wasm_bindgen_test]
+    async fn test_take() {
+        let limits = RateLimits::new(MemoryBuckets::default());
+        assert_eq!(limits.take("a", &LIMIT, 0).await.unwrap(), Decision::Allow);
+        assert_eq!(
+            limits.take("a", &LIMIT, 0).await.unwrap(),
+            Decision::Deny { retry_after: 2 }
+        );
+        assert_eq!(limits.take("b", &LIMIT, 0).await.unwrap(), Decision::Allow);
+        assert_eq!(
+            limits.take("a", &LIMIT, 2000).await.unwrap(),
+            Decision::Allow
+        );
+    }
+}
@@ -1,12 +1,18 @@
 mod jwt;
 mod kv;
+mod rate_limit;
 mod types;
 
 use crate::jwt::Claims;
+use crate::kv::rate_limits::{BucketStore, RateLimits};
+use crate::rate_limit::{Decision, Limit};
 use crate::types::{
-    BuildMetadata, ListViewerBuildResponse, PostViewerBuildRequest, PostViewerBuildResponse,
+    BuildMetadata, BuildValue, GetViewerBuildResponse, ListViewerBuildResponse,
+    PostViewerBuildRequest, PostViewerBuildResponse, PutViewerBuildRequest, PutViewerBuildResponse,
 };
+use build_format::BuildExport;
 use jsonwebtoken::TokenData;
+use uuid::Uuid;
 use worker::*;
 
 #[event(start)]
@@ -39,23 +45,231 @@ async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
                 .collect::<Vec<(String, BuildMetadata)>>();
             Response::from_json(&ListViewerBuildResponse { items })
         })
-        .post_async("/viewer/build", |mut req, ctx| async move {
+        .post_async("/viewer/build", |req, ctx| async move {
+            post_build(req, ctx, |body, user_id| {
+                let build = serde_json::from_str::<PostViewerBuildRequest>(body)?;
+                console_debug!("req: {:?}", build);
+                let value = BuildValue {
+                    user_id: user_id.to_string(),
+                    body: build.value.body,
+                };
+                Ok((value, build.metadata))
+            })
+            .await
+        })
+        .get_async("/viewer/build/:id", |req, ctx| async move {
             let token = require_auth(&req, &ctx).await?;
-            let build = req.json::<PostViewerBuildRequest>().await?;
-            console_debug!("req: {:?}", build);
+            let Some(id) = parse_build_id(&ctx) else {
+                return Response::error("Bad Request", 400);
+            };
             let store = ctx.kv("BUILDS")?;
             let repo = kv::builds::Builds::new(store);
-            let r = repo
-                .put_user_build(token.claims.sub, build.value, build.metadata)
-                .await?;
-            Response::from_json(&PostViewerBuildResponse { id: r.to_string() })
+            match repo.get_user_build(&token.claims.sub, &id).await? {
+                Some((value, metadata)) => {
+                    Response::from_json(&GetViewerBuildResponse { value, metadata })
+                }
+                None => Response::error("Not Found", 404),
+            }
+        })
+        .put_async("/viewer/build/:id", |req, ctx| async move {
+            put_build(req, ctx).await
+        })
+        .post_async("/viewer/build/import", |req, ctx| async move {
+            post_build(req, ctx, |body, user_id| {
+                let export =
+                    BuildExport::from_json(body).map_err(|e| Error::RustError(e.to_string()))?;
+                let value = BuildValue {
+                    user_id: user_id.to_string(),
+                    body: export.body,
+                };
+                Ok((value, export.metadata))
+            })
+            .await
         })
         .run(req, env)
         .await
 }
 
+/// Stores a new build for the authenticated user, applying rate limits and quotas.
+/// `parse` turns the request body into the value and metadata to store.
+async fn post_build(
+    mut req: Request,
+    ctx: RouteContext<()>,
+    parse: impl FnOnce(&str, &str) -> Result<(BuildValue, BuildMetadata)>,
+) -> Result<Response> {
+    if let Some(res) = require_ip_rate_limit(&req, &ctx).await? {
+        return Ok(res);
+    }
+    let token = require_auth(&req, &ctx).await?;
+    let key = format!("user_id={}", token.claims.sub);
+    if let Some(res) = require_rate_limit(&ctx, &key, &rate_limit::USER_LIMIT).await? {
+        return Ok(res);
+    }
+
+    if let Some(res) = require_content_length(&req)? {
+        return Ok(res);
+    }
+    let body = req.text().await?;
+    if body.len() > rate_limit::MAX_BODY_SIZE {
+        return Response::error("Payload Too Large", 413);
+    }
+    let (value, mut metadata) = parse(&body, &token.claims.sub)?;
+    let now = Date::now().as_millis();
+    metadata.created_at = now;
+    metadata.updated_at = now;
+
+    let store = ctx.kv("BUILDS")?;
+    let repo = kv::builds::Builds::new(store);
+    // The quota is a permanent cap rather than a rate, so there is no time after which
+    // retrying would succeed; answer `403 Forbidden` instead of `429` with `Retry-After`.
+    if repo.count_user_build(&token.claims.sub).await? >= rate_limit::MAX_BUILDS_PER_USER {
+        return Response::error("Build quota exceeded", 403);
+    }
+    let r = repo
+        .put_user_build(token.claims.sub, value, metadata)
+        .await?;
+    Response::from_json(&PostViewerBuildResponse { id: r.to_string() })
+}
+
+/// Updates a build of the authenticated user.
+/// Applies the same rate limits and body size limit as [`post_build`].
+/// Rejects the update with `409 Conflict` when the stored build changed since `base_updated_at`.
+async fn put_build(mut req: Request, ctx: RouteContext<()>) -> Result<Response> {
+    if let Some(res) = require_ip_rate_limit(&req, &ctx).await? {
+        return Ok(res);
+    }
+    let token = require_auth(&req, &ctx).await?;
+    let key = format!("user_id={}", token.claims.sub);
+    if let Some(res) = require_rate_limit(&ctx, &key, &rate_limit::USER_LIMIT).await? {
+        return Ok(res);
+    }
+    let Some(id) = parse_build_id(&ctx) else {
+        return Response::error("Bad Request", 400);
+    };
+
+    if let Some(res) = require_content_length(&req)? {
+        return Ok(res);
+    }
+    let body = req.text().await?;
+    if body.len() > rate_limit::MAX_BODY_SIZE {
+        return Response::error("Payload Too Large", 413);
+    }
+    let build = serde_json::from_str::<PutViewerBuildRequest>(&body)?;
+
+    let store = ctx.kv("BUILDS")?;
+    let repo = kv::builds::Builds::new(store);
+    let Some((_, current)) = repo.get_user_build(&token.claims.sub, &id).await? else {
+        return Response::error("Not Found", 404);
+    };
+    if let Some(base) = build.base_updated_at {
+        if base != current.updated_at {
+            return Response::error("Conflict", 409);
+        }
+    }
+
+    let metadata = BuildMetadata {
+        created_at: current.created_at,
+        updated_at: Date::now().as_millis().max(current.updated_at + 1),
+        ..build.metadata
+    };
+    let value = BuildValue {
+        user_id: token.claims.sub.clone(),
+        body: build.body,
+    };
+    repo.update_user_build(&token.claims.sub, &id, value, metadata.clone())
+        .await?;
+    Response::from_json(&PutViewerBuildResponse { metadata })
+}
+
+fn parse_build_id(ctx: &RouteContext<()>) -> Option<Uuid> {
+    ctx.param("id").and_then(|id| Uuid::parse_str(id).ok())
+}
+
 async fn require_auth(req: &Request, ctx: &RouteContext<()>) -> Result<TokenData<Claims>> {
     jwt::verify_jwt(req, &ctx.env)
         .await
         .map_err(|e| Error::RustError(e.to_string()))
 }
+
+/// Takes a token from the bucket of the client IP address, when the request carries one.
+async fn require_ip_rate_limit(req: &Request, ctx: &RouteContext<()>) -> Result<Option<Response>> {
+    let Some(ip) = req.headers().get("cf-connecting-ip")? else {
+        return Ok(None);
+    };
+    let key = format!("ip={}", ip);
+    require_rate_limit(ctx, &key, &rate_limit::IP_LIMIT).await
+}
+
+/// Rejects the request with `413 Payload Too Large` before reading a body whose declared
+/// length exceeds the limit.
+fn require_content_length(req: &Request) -> Result<Option<Response>> {
+    let content_length = req
+        .headers()
+        .get("content-length")?
+        .and_then(|v| v.parse::<usize>().ok());
+    if content_length.unwrap_or(0) > rate_limit::MAX_BODY_SIZE {
+        return Ok(Some(Response::error("Payload Too Large", 413)?));
+    }
+    Ok(None)
+}
+
+/// Takes a token from the bucket identified by `key`.
+/// Returns a `429 Too Many Requests` response when the bucket is empty.
+async fn require_rate_limit(
+    ctx: &RouteContext<()>,
+    key: &str,
+    limit: &Limit,
+) -> Result<Option<Response>> {
+    let store = kv::rate_limits::KvBuckets(ctx.kv("RATE_LIMITS")?);
+    let repo = kv::rate_limits::RateLimits::new(store);
+    take_rate_limit(&repo, key, limit, Date::now().as_millis()).await
+}
+
+async fn take_rate_limit<S: BucketStore>(
+    repo: &RateLimits<S>,
+    key: &str,
+    limit: &Limit,
+    now: u64,
+) -> Result<Option<Response>> {
+    match repo.take(key, limit, now).await? {
+        Decision::Allow => Ok(None),
+        Decision::Deny { retry_after } => {
+            let mut headers = Headers::new();
+            headers.set("retry-after", &retry_after.to_string())?;
+            Ok(Some(
+                Response::error("Too Many Requests", 429)?.with_headers(headers),
+            ))
+        }
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod test {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    use crate::kv::rate_limits::MemoryBuckets;
+
+    #[wasm_bindgen_test]
+    async fn test_rate_limit_response() {
+        let repo = RateLimits::new(MemoryBuckets::default());
+        let limit = Limit {
+            capacity: 1.0,
+            refill_per_sec: 0.5,
+        };
+        let res = take_rate_limit(&repo, "user_id=a", &limit, 0)
+            .await
+            .unwrap();
+        assert!(res.is_none());
+
+        let res = take_rate_limit(&repo, "user_id=a", &limit, 0)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(res.status_code(), 429);
+        assert_eq!(
+            res.headers().get("retry-after").unwrap(),
+            Some("2".to_string())
+        );
+    }
+}
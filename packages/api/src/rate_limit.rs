@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of builds a single user can store.
+pub const MAX_BUILDS_PER_USER: usize = 100;
+/// Maximum size of a build request body in bytes.
+pub const MAX_BODY_SIZE: usize = 64 * 1024;
+
+/// Write requests allowed per authenticated user.
+pub const USER_LIMIT: Limit = Limit {
+    capacity: 10.0,
+    refill_per_sec: 1.0 / 6.0,
+};
+/// Write requests allowed per client IP address, shared by all users behind it.
+pub const IP_LIMIT: Limit = Limit {
+    capacity: 30.0,
+    refill_per_sec: 0.5,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limit {
+    /// Maximum number of tokens (burst size).
+    pub capacity: f64,
+    /// Tokens added per second.
+    pub refill_per_sec: f64,
+}
+
+impl Limit {
+    /// Seconds until an untouched bucket is full again, used as the storage TTL.
+    pub fn ttl(&self) -> u64 {
+        (self.capacity / self.refill_per_sec).ceil() as u64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Allow,
+    Deny { retry_after: u64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenBucket {
+    pub tokens: f64,
+    /// Unix time in milliseconds.
+    pub updated_at: u64,
+}
+
+impl TokenBucket {
+    pub fn full(limit: &Limit, now: u64) -> Self {
+        Self {
+            tokens: limit.capacity,
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, limit: &Limit, now: u64) {
+        let elapsed = now.saturating_sub(self.updated_at) as f64 / 1000.0;
+        self.tokens = (self.tokens + elapsed * limit.refill_per_sec).min(limit.capacity);
+        self.updated_at = now;
+    }
+
+    /// Takes one token from the bucket.
+    /// When the bucket is empty, returns the number of seconds until a token is available.
+    pub fn take(&mut self, limit: &Limit, now: u64) -> Decision {
+        self.refill(limit, now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Decision::Allow
+        } else {
+            let retry_after = ((1.0 - self.tokens) / limit.refill_per_sec).ceil() as u64;
+            Decision::Deny {
+                retry_after: retry_after.max(1),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LIMIT: Limit = Limit {
+        capacity: 2.0,
+        refill_per_sec: 0.5,
+    };
+
+    #[test]
+    fn test_take() {
+        let mut bucket = TokenBucket::full(&LIMIT, 0);
+        assert_eq!(bucket.take(&LIMIT, 0), Decision::Allow);
+        assert_eq!(bucket.take(&LIMIT, 0), Decision::Allow);
+        assert_eq!(bucket.take(&LIMIT, 0), Decision::Deny { retry_after: 2 });
+        assert_eq!(bucket.take(&LIMIT, 1000), Decision::Deny { retry_after: 1 });
+        assert_eq!(bucket.take(&LIMIT, 2000), Decision::Allow);
+    }
+
+    #[test]
+    fn test_refill_is_capped() {
+        let mut bucket = TokenBucket::full(&LIMIT, 0);
+        assert_eq!(bucket.take(&LIMIT, 0), Decision::Allow);
+        assert_eq!(bucket.take(&LIMIT, 60_000), Decision::Allow);
+        assert_eq!(bucket.tokens, 1.0);
+    }
+}
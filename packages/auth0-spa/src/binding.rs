@@ -234,7 +234,7 @@ pub mod structs {
 
     #[derive(Debug, Clone, Deserialize)]
     pub struct GetTokenSilentlyVerboseResponse {
-        access_token: String,
+        pub access_token: String,
         expires_in: u32,
         id_token: String,
         refresh_token: Option<String>,
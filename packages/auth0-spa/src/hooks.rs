@@ -93,18 +93,35 @@ pub fn use_auth0_context<TAppState: Clone + Serialize>() -> UseAuth0<TAppState>
     UseAuth0 { context, channel }
 }
 
-#[derive(Copy, Clone)]
 pub struct UseAuth0<TAppState: Clone + Serialize + 'static> {
     context: Auth0Context,
     channel: Coroutine<Action<TAppState>>,
 }
 
+// Derived impls would require `TAppState: Copy`
+impl<TAppState: Clone + Serialize> Clone for UseAuth0<TAppState> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<TAppState: Clone + Serialize> Copy for UseAuth0<TAppState> {}
+
 impl<TAppState: Clone + Serialize> UseAuth0<TAppState> {
     /// Returns true if there's valid information stored, otherwise returns false.
     pub fn is_authenticated(&self) -> Signal<bool> {
         self.context.is_authenticated
     }
 
+    /// Returns the access token fetched by the last `get_token_silently`.
+    pub fn access_token(&self) -> Option<String> {
+        self.context
+            .token
+            .read()
+            .as_ref()
+            .map(|t| t.access_token.clone())
+    }
+
     pub fn get_token_silently(&self, options: GetTokenSilentlyOptions) {
         self.channel.send(Action::GetTokenSilently(options))
     }
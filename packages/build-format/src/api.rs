@@ -0,0 +1,48 @@
+//! Request and response types of the build API, shared by the worker and the app.
+
+use serde::{Deserialize, Serialize};
+
+use crate::BuildMetadata;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildValue {
+    pub user_id: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostViewerBuildRequest {
+    pub value: BuildValue,
+    pub metadata: BuildMetadata,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostViewerBuildResponse {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListViewerBuildResponse {
+    pub items: Vec<(String, BuildMetadata)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetViewerBuildResponse {
+    pub value: BuildValue,
+    pub metadata: BuildMetadata,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PutViewerBuildRequest {
+    pub body: String,
+    pub metadata: BuildMetadata,
+    /// `updated_at` of the version the edit is based on.
+    /// The update is rejected with `409 Conflict` when the stored build is newer.
+    /// `None` overwrites unconditionally.
+    pub base_updated_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PutViewerBuildResponse {
+    pub metadata: BuildMetadata,
+}
@@ -0,0 +1,467 @@
+//! Portable build file format.
+//!
+//! A build is exported as a single JSON object:
+//!
+//! ```json
+//! {
+//!   "format": 1,
+//!   "dataset": "1.0.9",
+//!   "language": "en",
+//!   "metadata": { "is_private": false, "title": "...", "created_at": 0, "updated_at": 0 },
+//!   "body": "markdown with <skill:...> and <rune:...> references",
+//!   "skills": [{ "id": "...", "name": "..." }],
+//!   "runes": [{ "id": "...", "name": "..." }],
+//!   "states": [{ "id": "...", "name": "..." }],
+//!   "enemies": [{ "id": "...", "name": "..." }]
+//! }
+//! ```
+//!
+//! - `format` is [`FORMAT_VERSION`]. Files with a newer format are rejected.
+//! - `dataset` is the game data version the build was written against.
+//! - `language` is the language of the names in `body` and the entity lists.
+//! - `skills`, `runes`, `states` and `enemies` list every reference in `body` that
+//!   resolved at export time,
+//!   so the references can be remapped by id when the build is imported in another
+//!   language or against another dataset.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use data::Database;
+
+use crate::{reference_spans, references, BuildMetadata, Reference, ReferenceKind, TermNames};
+
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("json error: {0}")]
+    Json(serde_json::Error),
+    #[error("unsupported format version: {0}")]
+    UnsupportedFormat(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EntityRef {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildExport {
+    pub format: u32,
+    pub dataset: String,
+    pub language: String,
+    pub metadata: BuildMetadata,
+    pub body: String,
+    pub skills: Vec<EntityRef>,
+    pub runes: Vec<EntityRef>,
+    #[serde(default)]
+    pub states: Vec<EntityRef>,
+    #[serde(default)]
+    pub enemies: Vec<EntityRef>,
+}
+
+/// Result of importing a [`BuildExport`] against the current database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    pub metadata: BuildMetadata,
+    /// Body with the exported references rewritten to stable ids.
+    pub body: String,
+    pub unknown_skills: Vec<EntityRef>,
+    pub unknown_runes: Vec<EntityRef>,
+    /// Unknown state and enemy references.
+    pub unknown_terms: Vec<EntityRef>,
+}
+
+impl Import {
+    pub fn is_complete(&self) -> bool {
+        self.unknown_skills.is_empty()
+            && self.unknown_runes.is_empty()
+            && self.unknown_terms.is_empty()
+    }
+}
+
+impl BuildExport {
+    pub fn new(
+        database: &Database,
+        names: &TermNames,
+        language: &str,
+        metadata: BuildMetadata,
+        body: String,
+    ) -> Self {
+        let mut export = Self {
+            format: FORMAT_VERSION,
+            dataset: database.version.clone(),
+            language: language.to_string(),
+            metadata,
+            body: String::new(),
+            skills: vec![],
+            runes: vec![],
+            states: vec![],
+            enemies: vec![],
+        };
+        for r in references(&body) {
+            let Some(item) = resolve(database, names, &r) else {
+                continue;
+            };
+            // A reference by id and one by name resolve to the same entity
+            let items = export.items_mut(r.kind);
+            if !items.iter().any(|i| i.id == item.id) {
+                items.push(item);
+            }
+        }
+        export.body = body;
+        export
+    }
+
+    fn items(&self, kind: ReferenceKind) -> &Vec<EntityRef> {
+        match kind {
+            ReferenceKind::Skill => &self.skills,
+            ReferenceKind::Rune => &self.runes,
+            ReferenceKind::State => &self.states,
+            ReferenceKind::Enemy => &self.enemies,
+        }
+    }
+
+    fn items_mut(&mut self, kind: ReferenceKind) -> &mut Vec<EntityRef> {
+        match kind {
+            ReferenceKind::Skill => &mut self.skills,
+            ReferenceKind::Rune => &mut self.runes,
+            ReferenceKind::State => &mut self.states,
+            ReferenceKind::Enemy => &mut self.enemies,
+        }
+    }
+
+    pub fn from_json(s: &str) -> Result<Self, Error> {
+        let export = serde_json::from_str::<BuildExport>(s).map_err(Error::Json)?;
+        if export.format > FORMAT_VERSION {
+            return Err(Error::UnsupportedFormat(export.format));
+        }
+        Ok(export)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("export should serialize")
+    }
+
+    /// Renders the build as a standalone markdown document.
+    /// References become plain bold names, so the output cannot be imported again.
+    pub fn to_markdown(&self) -> String {
+        let body = replace_references(&self.body, |r| {
+            Some(format!("**{}**", self.resolved_name(r).unwrap_or(&r.key)))
+        });
+
+        let quote = |s: &str| serde_json::to_string(s).expect("string should serialize");
+        let mut out = String::new();
+        out.push_str("---\n");
+        out.push_str(&format!("title: {}\n", quote(&self.metadata.title)));
+        out.push_str(&format!("dataset: {}\n", quote(&self.dataset)));
+        out.push_str(&format!("language: {}\n", quote(&self.language)));
+        out.push_str("---\n\n");
+        if !self.metadata.title.is_empty() {
+            out.push_str(&format!("# {}\n\n", self.metadata.title));
+        }
+        out.push_str(body.trim_end());
+        out.push('\n');
+
+        for (heading, items) in [
+            ("Skills", &self.skills),
+            ("Runes", &self.runes),
+            ("States", &self.states),
+            ("Enemies", &self.enemies),
+        ] {
+            if !items.is_empty() {
+                out.push_str(&format!("\n## {}\n\n", heading));
+                for item in items {
+                    out.push_str(&format!("- {} (`{}`)\n", item.name, item.id));
+                }
+            }
+        }
+        out
+    }
+
+    /// Name of an exported reference, which may be keyed by id or by name.
+    fn resolved_name(&self, r: &Reference) -> Option<&str> {
+        self.items(r.kind)
+            .iter()
+            .find(|item| item.id == r.key || item.name == r.key)
+            .map(|item| item.name.as_str())
+    }
+
+    /// Resolves the exported references against `database`.
+    /// Names are rewritten to ids, so the body no longer depends on the export language.
+    pub fn import(&self, database: &Database, names: &TermNames) -> Import {
+        let mut renames = HashMap::new();
+        let mut unknown_skills = vec![];
+        let mut unknown_runes = vec![];
+        let mut unknown_terms = vec![];
+
+        for kind in [
+            ReferenceKind::Skill,
+            ReferenceKind::Rune,
+            ReferenceKind::State,
+            ReferenceKind::Enemy,
+        ] {
+            for item in self.items(kind) {
+                let by_id = Reference {
+                    kind,
+                    key: item.id.clone(),
+                };
+                match resolve(database, names, &by_id) {
+                    Some(to) => {
+                        let from = Reference {
+                            kind,
+                            key: item.name.clone(),
+                        };
+                        renames.insert(from, Reference { kind, key: to.id });
+                    }
+                    None => match kind {
+                        ReferenceKind::Skill => unknown_skills.push(item.clone()),
+                        ReferenceKind::Rune => unknown_runes.push(item.clone()),
+                        ReferenceKind::State | ReferenceKind::Enemy => {
+                            unknown_terms.push(item.clone())
+                        }
+                    },
+                }
+            }
+        }
+        let body = replace_references(&self.body, |r| renames.get(r).map(|to| to.to_string()));
+
+        // References that did not resolve at export time
+        let mut seen = HashSet::new();
+        for r in references(&body) {
+            if r.is_resolved(database, names) || !seen.insert(r.clone()) {
+                continue;
+            }
+            let item = EntityRef {
+                id: String::new(),
+                name: r.key.clone(),
+            };
+            let unknown = match r.kind {
+                ReferenceKind::Skill => &mut unknown_skills,
+                ReferenceKind::Rune => &mut unknown_runes,
+                ReferenceKind::State | ReferenceKind::Enemy => &mut unknown_terms,
+            };
+            if !unknown.iter().any(|u| u.name == item.name) {
+                unknown.push(item);
+            }
+        }
+
+        Import {
+            metadata: self.metadata.clone(),
+            body,
+            unknown_skills,
+            unknown_runes,
+            unknown_terms,
+        }
+    }
+}
+
+/// The entity a reference points to, with its id and its name in `database`.
+fn resolve(database: &Database, names: &TermNames, r: &Reference) -> Option<EntityRef> {
+    match r.kind {
+        ReferenceKind::Skill => r.resolve_skill(database).map(|skill| EntityRef {
+            id: skill.id.clone(),
+            name: skill.name.clone(),
+        }),
+        ReferenceKind::Rune => r.resolve_rune(database).map(|rune| EntityRef {
+            id: rune.id.clone(),
+            name: rune.name.clone(),
+        }),
+        ReferenceKind::State | ReferenceKind::Enemy => r.resolve_term(names).map(|id| EntityRef {
+            id: id.to_string(),
+            name: format!("{}", database.term.get(&format!("NM-{}", id))),
+        }),
+    }
+}
+
+/// Rewrites every reference in `body` in a single pass.
+/// References for which `f` returns `None` are kept as they are.
+fn replace_references<F: FnMut(&Reference) -> Option<String>>(body: &str, mut f: F) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut pos = 0;
+    for (span, r) in reference_spans(body) {
+        if let Some(to) = f(&r) {
+            out.push_str(&body[pos..span.start]);
+            out.push_str(&to);
+            pos = span.end;
+        }
+    }
+    out.push_str(&body[pos..]);
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use data::skill::{Skill, SkillCategory, SkillRepository};
+    use data::term::{Term, TermRepository};
+    use data::token::{Token, Tokens};
+    use data::GlobalRepository;
+
+    use super::*;
+
+    fn skill(hash: u16, id: &str, name: &str) -> Skill {
+        Skill {
+            hash,
+            id: id.to_string(),
+            modes: vec![],
+            category: SkillCategory::Attack,
+            rarity: 1,
+            in_dictionary: true,
+            is_free: false,
+            name: name.to_string(),
+        }
+    }
+
+    fn database(skills: Vec<Skill>) -> Database {
+        Database {
+            skill: Rc::new(SkillRepository::from_vec(skills)),
+            ..Default::default()
+        }
+    }
+
+    fn export() -> BuildExport {
+        BuildExport::new(
+            &Database::default(),
+            &TermNames::default(),
+            "en",
+            BuildMetadata {
+                title: "Title".to_string(),
+                ..Default::default()
+            },
+            "<skill:abc> is strong.\n".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_json() {
+        let export = export();
+        assert_eq!(BuildExport::from_json(&export.to_json()).unwrap(), export);
+
+        let future = BuildExport {
+            format: FORMAT_VERSION + 1,
+            ..export
+        };
+        assert!(matches!(
+            BuildExport::from_json(&future.to_json()),
+            Err(Error::UnsupportedFormat(_))
+        ));
+        assert!(matches!(BuildExport::from_json("{}"), Err(Error::Json(_))));
+    }
+
+    #[test]
+    fn test_markdown() {
+        assert_eq!(
+            export().to_markdown(),
+            "---\ntitle: \"Title\"\ndataset: \"\"\nlanguage: \"en\"\n---\n\n# Title\n\n**abc** is strong.\n"
+        );
+    }
+
+    #[test]
+    fn test_import_unknown() {
+        let import = export().import(&Database::default(), &TermNames::default());
+        assert!(!import.is_complete());
+        assert_eq!(
+            import.unknown_skills,
+            vec![EntityRef {
+                id: String::new(),
+                name: "abc".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_markdown_names() {
+        let database = database(vec![skill(1, "SK_FIRE", "Fire")]);
+        let export = BuildExport::new(
+            &database,
+            &TermNames::default(),
+            "en",
+            BuildMetadata::default(),
+            "<skill:SK_FIRE> and <skill:Fire>".to_string(),
+        );
+        assert!(export
+            .to_markdown()
+            .ends_with("**Fire** and **Fire**\n\n## Skills\n\n- Fire (`SK_FIRE`)\n"));
+    }
+
+    #[test]
+    fn test_import_rename() {
+        let en = database(vec![skill(1, "SK_FIRE", "Fire"), skill(2, "SK_ICE", "Ice")]);
+        let export = BuildExport::new(
+            &en,
+            &TermNames::default(),
+            "en",
+            BuildMetadata::default(),
+            "<skill:Fire>, <skill:Ice> and <skill:Fire>".to_string(),
+        );
+
+        // Names resolve by their exported id, even when a new name is another old name
+        let renamed = database(vec![
+            skill(1, "SK_FIRE", "Ice"),
+            skill(2, "SK_ICE", "Water"),
+        ]);
+        let import = export.import(&renamed, &TermNames::default());
+        assert!(import.is_complete());
+        assert_eq!(
+            import.body,
+            "<skill:SK_FIRE>, <skill:SK_ICE> and <skill:SK_FIRE>"
+        );
+    }
+
+    fn terms(names: &[(&str, &str)]) -> Database {
+        let term = |text: &str| Term {
+            tokens: Tokens::from_vec(vec![Token::Text(text.to_string())]),
+        };
+        Database {
+            global: GlobalRepository {
+                state_ids: vec!["Burn".to_string()],
+                enemy_ids: vec!["Slime".to_string()],
+                ..Default::default()
+            },
+            term: Rc::new(TermRepository::from_vec(
+                names
+                    .iter()
+                    .map(|(id, name)| (format!("NM-{}", id), term(name)))
+                    .collect(),
+            )),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_import_terms() {
+        let en = terms(&[("Burn", "Burning"), ("Slime", "Green Slime")]);
+        let export = BuildExport::new(
+            &en,
+            &TermNames::new(&en),
+            "en",
+            BuildMetadata::default(),
+            "<state:Burning> <enemy:Green Slime> <enemy:Burning>".to_string(),
+        );
+        assert_eq!(
+            export.states,
+            vec![EntityRef {
+                id: "Burn".to_string(),
+                name: "Burning".to_string()
+            }]
+        );
+        assert_eq!(export.enemies.len(), 1);
+
+        let ja = terms(&[("Burn", "燃焼"), ("Slime", "スライム")]);
+        let import = export.import(&ja, &TermNames::new(&ja));
+        assert_eq!(import.body, "<state:Burn> <enemy:Slime> <enemy:Burning>");
+        assert_eq!(
+            import.unknown_terms,
+            vec![EntityRef {
+                id: String::new(),
+                name: "Burning".to_string()
+            }]
+        );
+    }
+}
@@ -0,0 +1,8 @@
+pub use export::*;
+pub use metadata::*;
+pub use reference::*;
+
+pub mod api;
+mod export;
+mod metadata;
+mod reference;
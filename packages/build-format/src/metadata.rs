@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BuildMetadata {
+    pub is_private: bool,
+    pub title: String,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
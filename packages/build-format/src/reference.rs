@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use data::skill::Skill;
+use data::{Database, Rune};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReferenceKind {
+    Skill,
+    Rune,
+    State,
+    Enemy,
+}
+
+impl ReferenceKind {
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            ReferenceKind::Skill => "skill",
+            ReferenceKind::Rune => "rune",
+            ReferenceKind::State => "state",
+            ReferenceKind::Enemy => "enemy",
+        }
+    }
+
+    fn from_prefix(s: &str) -> Option<Self> {
+        match s {
+            "skill" => Some(ReferenceKind::Skill),
+            "rune" => Some(ReferenceKind::Rune),
+            "state" => Some(ReferenceKind::State),
+            "enemy" => Some(ReferenceKind::Enemy),
+            _ => None,
+        }
+    }
+}
+
+/// A `<kind:key>` reference in a build body.
+/// `key` is a stable id, or a localized name which only resolves in the same language.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Reference {
+    pub kind: ReferenceKind,
+    pub key: String,
+}
+
+impl Reference {
+    pub fn parse(s: &str) -> Option<Self> {
+        let (prefix, key) = s.split_once(':')?;
+        let kind = ReferenceKind::from_prefix(prefix)?;
+        if key.is_empty() {
+            None
+        } else {
+            Some(Reference {
+                kind,
+                key: key.to_string(),
+            })
+        }
+    }
+
+    /// Parses a reference at the start of `s`, returning it with the number of bytes consumed.
+    pub fn parse_prefix(s: &str) -> Option<(Self, usize)> {
+        let tail = s.strip_prefix('<')?;
+        let end = tail.find(['<', '>', '\n'])?;
+        if !tail[end..].starts_with('>') {
+            return None;
+        }
+        let r = Reference::parse(&tail[..end])?;
+        Some((r, end + 2))
+    }
+
+    pub fn resolve_skill<'a>(&self, database: &'a Database) -> Option<&'a Skill> {
+        match self.kind {
+            ReferenceKind::Skill => database
+                .skill
+                .iter()
+                .find(|s| s.id == self.key)
+                .or_else(|| database.skill.find(&self.key)),
+            _ => None,
+        }
+    }
+
+    pub fn resolve_rune<'a>(&self, database: &'a Database) -> Option<&'a Rune> {
+        match self.kind {
+            ReferenceKind::Rune => database
+                .rune
+                .iter()
+                .find(|r| r.id == self.key)
+                .or_else(|| database.rune.find(&self.key)),
+            _ => None,
+        }
+    }
+
+    /// Resolves a state or enemy reference to the id of its term.
+    /// The name is available as `NM-{id}` and tips as `DC-{id}`.
+    pub fn resolve_term<'a>(&self, names: &'a TermNames) -> Option<&'a str> {
+        let map = match self.kind {
+            ReferenceKind::State => &names.states,
+            ReferenceKind::Enemy => &names.enemies,
+            _ => return None,
+        };
+        map.get(&self.key).map(String::as_str)
+    }
+
+    pub fn is_resolved(&self, database: &Database, names: &TermNames) -> bool {
+        match self.kind {
+            ReferenceKind::Skill => self.resolve_skill(database).is_some(),
+            ReferenceKind::Rune => self.resolve_rune(database).is_some(),
+            ReferenceKind::State | ReferenceKind::Enemy => self.resolve_term(names).is_some(),
+        }
+    }
+}
+
+/// The term ids of the states and enemies by id and by name, built once per database.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TermNames {
+    states: HashMap<String, String>,
+    enemies: HashMap<String, String>,
+}
+
+impl TermNames {
+    pub fn new(database: &Database) -> Self {
+        let global = &database.global;
+        if global.state_ids.is_empty() && global.enemy_ids.is_empty() {
+            // Databases generated before the ids were recorded can't tell states from
+            // enemies, so any name term resolves as either.
+            let ids = database
+                .term
+                .keys()
+                .filter_map(|k| k.strip_prefix("NM-"))
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+            let states = Self::map(database, &ids);
+            return TermNames {
+                enemies: states.clone(),
+                states,
+            };
+        }
+        TermNames {
+            states: Self::map(database, &global.state_ids),
+            enemies: Self::map(database, &global.enemy_ids),
+        }
+    }
+
+    /// Maps the name and the id of each term to its id. Ids win over equal names.
+    fn map(database: &Database, ids: &[String]) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        for id in ids {
+            if let Some(name) = database.term.try_get(&format!("NM-{}", id)) {
+                map.insert(format!("{}", name), id.clone());
+            }
+        }
+        for id in ids {
+            if database.term.contains_key(&format!("NM-{}", id)) {
+                map.insert(id.clone(), id.clone());
+            }
+        }
+        map
+    }
+}
+
+impl Display for Reference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}:{}>", self.kind.prefix(), self.key)
+    }
+}
+
+/// Collects every reference in `body`, in order of appearance.
+pub fn references(body: &str) -> Vec<Reference> {
+    reference_spans(body).into_iter().map(|(_, r)| r).collect()
+}
+
+/// Collects every reference in `body` with its byte range, in order of appearance.
+pub fn reference_spans(body: &str) -> Vec<(Range<usize>, Reference)> {
+    let mut out = vec![];
+    let mut pos = 0;
+    while let Some(start) = body[pos..].find('<') {
+        let at = pos + start;
+        match Reference::parse_prefix(&body[at..]) {
+            Some((r, len)) => {
+                out.push((at..at + len, r));
+                pos = at + len;
+            }
+            None => pos = at + 1,
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn skill(key: &str) -> Reference {
+        Reference {
+            kind: ReferenceKind::Skill,
+            key: key.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_references() {
+        assert_eq!(references(""), vec![]);
+        assert_eq!(references("<skill:>"), vec![]);
+        assert_eq!(references("<item:abc>"), vec![]);
+        assert_eq!(references("<skill:abc>"), vec![skill("abc")]);
+        assert_eq!(
+            references("a <skill:abc> b <rune:def> <skill:ghi>"),
+            vec![
+                skill("abc"),
+                Reference {
+                    kind: ReferenceKind::Rune,
+                    key: "def".to_string()
+                },
+                skill("ghi")
+            ]
+        );
+        assert_eq!(references("<<skill:abc>"), vec![skill("abc")]);
+        assert_eq!(references("<skill:a\nbc> <skill:def>"), vec![skill("def")]);
+        assert_eq!(references("<skill:a b>"), vec![skill("a b")]);
+        assert_eq!(skill("abc").to_string(), "<skill:abc>");
+        assert_eq!(
+            Reference::parse_prefix("<skill:abc> def"),
+            Some((skill("abc"), 11))
+        );
+        assert_eq!(Reference::parse_prefix(" <skill:abc>"), None);
+        assert_eq!(
+            reference_spans("a <skill:abc>"),
+            vec![(2..13, skill("abc"))]
+        );
+    }
+
+    #[test]
+    fn test_term_names() {
+        use std::rc::Rc;
+
+        use data::term::{Term, TermRepository};
+        use data::token::{Token, Tokens};
+
+        let term = |text: &str| Term {
+            tokens: Tokens::from_vec(vec![Token::Text(text.to_string())]),
+        };
+        let mut database = Database {
+            term: Rc::new(TermRepository::from_vec(vec![
+                ("NM-Burn".to_string(), term("Burn")),
+                ("NM-Slime".to_string(), term("Slime")),
+                ("NM-Bat".to_string(), term("Burn")),
+            ])),
+            ..Default::default()
+        };
+        let state = |key: &str| Reference {
+            kind: ReferenceKind::State,
+            key: key.to_string(),
+        };
+        let enemy = |key: &str| Reference {
+            kind: ReferenceKind::Enemy,
+            key: key.to_string(),
+        };
+
+        // Without recorded ids, any name resolves as either kind
+        let names = TermNames::new(&database);
+        assert!(state("Slime").resolve_term(&names).is_some());
+        assert!(enemy("Burn").resolve_term(&names).is_some());
+
+        database.global.state_ids = vec!["Burn".to_string()];
+        database.global.enemy_ids = vec!["Slime".to_string(), "Bat".to_string()];
+        let names = TermNames::new(&database);
+        assert_eq!(state("Burn").resolve_term(&names), Some("Burn"));
+        assert_eq!(state("Slime").resolve_term(&names), None);
+        assert_eq!(enemy("Slime").resolve_term(&names), Some("Slime"));
+        assert_eq!(enemy("Bat").resolve_term(&names), Some("Bat"));
+        assert_eq!(enemy("Burn").resolve_term(&names), Some("Bat"));
+        assert_eq!(skill("Burn").resolve_term(&names), None);
+    }
+}
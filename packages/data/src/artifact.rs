@@ -0,0 +1,184 @@
+//! The container of the files of the database: a header with the format and the dataset
+//! versions, then the msgpack payload compressed with brotli and its CRC-32.
+//!
+//! ```text
+//! "BB2B" | format: u16 | version length: u16 | version | payload length: u32 | crc32: u32 | payload
+//! ```
+//!
+//! Integers are little endian.
+
+use std::fmt::{Display, Formatter};
+use std::io::{self, Read};
+#[cfg(feature = "compress")]
+use std::io::Write;
+
+use serde::de::DeserializeOwned;
+#[cfg(feature = "compress")]
+use serde::Serialize;
+
+const MAGIC: &[u8; 4] = b"BB2B";
+/// Bumped on any change of the container or of the types inside.
+pub const FORMAT_VERSION: u16 = 1;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArtifactError {
+    /// Written in another format, usually by a newer version of the generator.
+    UnsupportedVersion(u16),
+    /// Not an artifact, or damaged.
+    Corrupt(String),
+    /// Ends before its payload.
+    Truncated,
+}
+
+impl Display for ArtifactError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArtifactError::UnsupportedVersion(v) => write!(f, "unsupported format version {}", v),
+            ArtifactError::Corrupt(reason) => write!(f, "corrupt data: {}", reason),
+            ArtifactError::Truncated => write!(f, "truncated data"),
+        }
+    }
+}
+
+impl std::error::Error for ArtifactError {}
+
+impl From<io::Error> for ArtifactError {
+    fn from(e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::UnexpectedEof => ArtifactError::Truncated,
+            _ => ArtifactError::Corrupt(e.to_string()),
+        }
+    }
+}
+
+pub struct Artifact<T> {
+    /// The version of the game data.
+    pub version: String,
+    pub value: T,
+}
+
+fn read_u16<R: Read>(read: &mut R) -> io::Result<u16> {
+    let mut buf = [0; 2];
+    read.read_exact(&mut buf)?;
+    Ok(u16::from_le_bytes(buf))
+}
+
+fn read_u32<R: Read>(read: &mut R) -> io::Result<u32> {
+    let mut buf = [0; 4];
+    read.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_bytes<R: Read>(read: &mut R, len: usize) -> Result<Vec<u8>, ArtifactError> {
+    let mut buf = vec![];
+    read.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() < len {
+        return Err(ArtifactError::Truncated);
+    }
+    Ok(buf)
+}
+
+pub fn read_artifact<R: Read, T: DeserializeOwned>(
+    mut read: R,
+) -> Result<Artifact<T>, ArtifactError> {
+    let mut magic = [0; 4];
+    read.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(ArtifactError::Corrupt("not an artifact".to_string()));
+    }
+    let format = read_u16(&mut read)?;
+    if format != FORMAT_VERSION {
+        return Err(ArtifactError::UnsupportedVersion(format));
+    }
+
+    let len = read_u16(&mut read)? as usize;
+    let version = String::from_utf8(read_bytes(&mut read, len)?)
+        .map_err(|_| ArtifactError::Corrupt("invalid version".to_string()))?;
+
+    let len = read_u32(&mut read)? as usize;
+    let checksum = read_u32(&mut read)?;
+    let payload = read_bytes(&mut read, len)?;
+    if crc32fast::hash(&payload) != checksum {
+        return Err(ArtifactError::Corrupt("checksum mismatch".to_string()));
+    }
+
+    let mut decoded = vec![];
+    brotli_decompressor::Decompressor::new(&payload[..], 4096)
+        .read_to_end(&mut decoded)
+        .map_err(|e| ArtifactError::Corrupt(e.to_string()))?;
+    let value =
+        rmp_serde::from_slice(&decoded).map_err(|e| ArtifactError::Corrupt(e.to_string()))?;
+    Ok(Artifact { version, value })
+}
+
+#[cfg(feature = "compress")]
+pub fn write_artifact<W: Write, T: Serialize>(
+    write: &mut W,
+    version: &str,
+    value: &T,
+) -> io::Result<()> {
+    let encoded = rmp_serde::to_vec(value).map_err(io::Error::other)?;
+    let mut payload = vec![];
+    {
+        let mut compressor = brotli::CompressorWriter::new(&mut payload, 4096, 9, 22);
+        compressor.write_all(&encoded)?;
+    }
+
+    write.write_all(MAGIC)?;
+    write.write_all(&FORMAT_VERSION.to_le_bytes())?;
+    write.write_all(&(version.len() as u16).to_le_bytes())?;
+    write.write_all(version.as_bytes())?;
+    write.write_all(&(payload.len() as u32).to_le_bytes())?;
+    write.write_all(&crc32fast::hash(&payload).to_le_bytes())?;
+    write.write_all(&payload)
+}
+
+#[cfg(all(test, feature = "compress"))]
+mod test {
+    use super::*;
+
+    fn artifact() -> Vec<u8> {
+        let mut buf = vec![];
+        write_artifact(&mut buf, "1.0.9", &vec!["skill".to_string(); 100]).unwrap();
+        buf
+    }
+
+    fn read(buf: &[u8]) -> Result<Artifact<Vec<String>>, ArtifactError> {
+        read_artifact(buf)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let artifact = read(&artifact()).unwrap();
+        assert_eq!(artifact.version, "1.0.9");
+        assert_eq!(artifact.value, vec!["skill".to_string(); 100]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let buf = artifact();
+        assert_eq!(
+            read(&buf[..buf.len() - 1]).err(),
+            Some(ArtifactError::Truncated)
+        );
+        assert_eq!(read(&buf[..3]).err(), Some(ArtifactError::Truncated));
+
+        let mut corrupt = buf.clone();
+        *corrupt.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            read(&corrupt).err(),
+            Some(ArtifactError::Corrupt("checksum mismatch".to_string()))
+        );
+        assert!(matches!(
+            read(b"PK\x03\x04...."),
+            Err(ArtifactError::Corrupt(_))
+        ));
+
+        let mut newer = buf.clone();
+        newer[4..6].copy_from_slice(&2u16.to_le_bytes());
+        assert_eq!(
+            read(&newer).err(),
+            Some(ArtifactError::UnsupportedVersion(2))
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::io::Read;
+#[cfg(feature = "compress")]
+use std::io::Write;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "compress")]
+use artifact::write_artifact;
+use artifact::{read_artifact, ArtifactError};
+use skill::{Skill, SkillCategory, SkillHash, SkillRepository};
+use term::Term;
+use {Database, GlobalRepository};
+use {Rune, RuneHash, RuneRepository, Sprite};
+
+/// What the lists need to show a skill before its chunk is loaded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillSummary {
+    pub hash: SkillHash,
+    pub id: String,
+    pub name: String,
+    pub icon: Sprite,
+    pub rarity: i8,
+    pub category: SkillCategory,
+    pub is_free: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuneSummary {
+    pub hash: RuneHash,
+    pub id: String,
+    pub name: String,
+    pub icon: Sprite,
+    pub rarity: i8,
+}
+
+/// The small part of the database loaded first, in the order of the repositories.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatabaseIndex {
+    pub version: String,
+    pub global: GlobalRepository,
+    pub skills: Vec<SkillSummary>,
+    pub runes: Vec<RuneSummary>,
+}
+
+impl DatabaseIndex {
+    pub fn read<R: Read>(read: R) -> Result<Self, ArtifactError> {
+        read_artifact(read).map(|a| a.value)
+    }
+
+    #[cfg(feature = "compress")]
+    pub fn write<W: Write>(&self, write: &mut W) -> std::io::Result<()> {
+        write_artifact(write, &self.version, self)
+    }
+}
+
+/// A part of the database loaded on demand: the terms, the skills of a category or the
+/// runes. The terms are sorted by key, so the same terms are written the same.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub enum DatabaseChunk {
+    Term(Vec<(String, Term)>),
+    Skill(Vec<Skill>),
+    Rune(Vec<Rune>),
+}
+
+impl DatabaseChunk {
+    pub fn read<R: Read>(read: R) -> Result<Self, ArtifactError> {
+        read_artifact(read).map(|a| a.value)
+    }
+
+    /// Writes the chunk of the database of game version `version`.
+    #[cfg(feature = "compress")]
+    pub fn write<W: Write>(&self, version: &str, write: &mut W) -> std::io::Result<()> {
+        write_artifact(write, version, self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkEntry {
+    /// `term`, `rune`, or `skill-<category>`.
+    pub name: String,
+    pub file: String,
+}
+
+/// The files of a language. Their names contain a hash of the content, so only the
+/// manifest has to be fetched again to find out about a new database.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Identifies the files below, changing with any of them. The service worker keys
+    /// its cache of the database by it.
+    pub version: String,
+    pub index: String,
+    pub search: String,
+    pub chunks: Vec<ChunkEntry>,
+}
+
+impl Manifest {
+    pub fn read<R: Read>(read: R) -> Result<Self, ArtifactError> {
+        read_artifact(read).map(|a| a.value)
+    }
+
+    #[cfg(feature = "compress")]
+    pub fn write<W: Write>(&self, write: &mut W) -> std::io::Result<()> {
+        write_artifact(write, &self.version, self)
+    }
+}
+
+impl Database {
+    pub fn index(&self) -> DatabaseIndex {
+        DatabaseIndex {
+            version: self.version.clone(),
+            global: self.global.clone(),
+            skills: self
+                .skill
+                .iter()
+                .map(|skill| SkillSummary {
+                    hash: skill.hash,
+                    id: skill.id.clone(),
+                    name: skill.name.clone(),
+                    icon: skill.modes[0].icon.clone(),
+                    rarity: skill.rarity,
+                    category: skill.category.clone(),
+                    is_free: skill.is_free,
+                })
+                .collect(),
+            runes: self
+                .rune
+                .iter()
+                .map(|rune| RuneSummary {
+                    hash: rune.hash,
+                    id: rune.id.clone(),
+                    name: rune.name.clone(),
+                    icon: rune.icon.clone(),
+                    rarity: rune.rarity,
+                })
+                .collect(),
+        }
+    }
+
+    /// Splits the repositories into chunks in the order to load them: the skills by
+    /// category, the runes, and the terms, which are only needed by the tooltips.
+    pub fn chunks(&self) -> Vec<(String, DatabaseChunk)> {
+        let mut chunks = vec![];
+
+        let mut categories: Vec<(SkillCategory, Vec<Skill>)> = vec![];
+        for skill in self.skill.iter() {
+            match categories.iter_mut().find(|(c, _)| *c == skill.category) {
+                Some((_, skills)) => skills.push(skill.clone()),
+                None => categories.push((skill.category.clone(), vec![skill.clone()])),
+            }
+        }
+        for (category, skills) in categories {
+            chunks.push((
+                format!("skill-{:?}", category).to_lowercase(),
+                DatabaseChunk::Skill(skills),
+            ));
+        }
+
+        chunks.push((
+            "rune".to_string(),
+            DatabaseChunk::Rune(self.rune.iter().cloned().collect()),
+        ));
+        let mut terms = self
+            .term
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Vec<_>>();
+        terms.sort_by(|a, b| a.0.cmp(&b.0));
+        chunks.push(("term".to_string(), DatabaseChunk::Term(terms)));
+        chunks
+    }
+
+    /// The database of the index, with empty repositories to fill with `insert_chunk`.
+    pub fn from_index(index: &DatabaseIndex) -> Self {
+        Database {
+            global: index.global.clone(),
+            version: index.version.clone(),
+            ..Database::default()
+        }
+    }
+
+    /// Adds a chunk to the repositories, keeping the order of the index.
+    pub fn insert_chunk(&mut self, index: &DatabaseIndex, chunk: DatabaseChunk) {
+        match chunk {
+            DatabaseChunk::Term(term) => {
+                let mut merged = self.term.as_ref().clone();
+                merged.extend(term);
+                self.term = Rc::new(merged);
+            }
+            DatabaseChunk::Skill(skills) => {
+                let order = index
+                    .skills
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| (s.hash, i))
+                    .collect::<HashMap<_, _>>();
+                let mut merged = self.skill.iter().cloned().collect::<Vec<_>>();
+                merged.extend(skills);
+                merged.sort_by_key(|s| order.get(&s.hash).cloned().unwrap_or(usize::MAX));
+                self.skill = Rc::new(SkillRepository::from_vec(merged));
+            }
+            DatabaseChunk::Rune(runes) => {
+                let order = index
+                    .runes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, r)| (r.hash, i))
+                    .collect::<HashMap<_, _>>();
+                let mut merged = self.rune.iter().cloned().collect::<Vec<_>>();
+                merged.extend(runes);
+                merged.sort_by_key(|r| order.get(&r.hash).cloned().unwrap_or(usize::MAX));
+                self.rune = Rc::new(RuneRepository::from_vec(merged));
+            }
+        }
+    }
+
+    pub fn from_chunks<I: IntoIterator<Item = DatabaseChunk>>(
+        index: &DatabaseIndex,
+        chunks: I,
+    ) -> Self {
+        let mut database = Database::from_index(index);
+        for chunk in chunks {
+            database.insert_chunk(index, chunk);
+        }
+        database
+    }
+}
@@ -1,8 +1,13 @@
-use std::io::{Read, Write};
+use std::io::Read;
+#[cfg(feature = "compress")]
+use std::io::Write;
 use std::rc::Rc;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "compress")]
+use artifact::write_artifact;
+use artifact::{read_artifact, ArtifactError};
 use {rune, term};
 use {skill, GlobalRepository};
 
@@ -12,14 +17,18 @@ pub struct Database {
     pub term: Rc<term::TermRepository>,
     pub skill: Rc<skill::SkillRepository>,
     pub rune: Rc<rune::RuneRepository>,
+    /// Version of the game data this database was generated from.
+    #[serde(default)]
+    pub version: String,
 }
 
 impl Database {
-    pub fn read<R: Read>(read: R) -> Result<Self, rmp_serde::decode::Error> {
-        rmp_serde::decode::from_read(read)
+    pub fn read<R: Read>(read: R) -> Result<Self, ArtifactError> {
+        read_artifact(read).map(|a| a.value)
     }
 
-    pub fn write<W: Write>(&self, write: &mut W) -> Result<(), rmp_serde::encode::Error> {
-        rmp_serde::encode::write(write, self)
+    #[cfg(feature = "compress")]
+    pub fn write<W: Write>(&self, write: &mut W) -> std::io::Result<()> {
+        write_artifact(write, &self.version, self)
     }
 }
@@ -0,0 +1,569 @@
+//! The structure of a description, with terms nested as trees, and renderers to
+//! output it outside of the web app.
+
+use std::fmt::Write;
+
+use token::{Style, Token, Tokens};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DescriptionNode {
+    Text(String),
+    NewLine,
+    /// New line and indent
+    Indent,
+    Var(String),
+    Error(String),
+    Term {
+        name: String,
+        /// The term explained in a tooltip.
+        tips: Option<String>,
+        children: Vec<DescriptionNode>,
+    },
+    Styled {
+        style: Style,
+        children: Vec<DescriptionNode>,
+    },
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DescriptionTree(Vec<DescriptionNode>);
+
+impl DescriptionTree {
+    pub fn new(tokens: &Tokens) -> Self {
+        // The open terms and styles, with the nodes read so far inside each
+        let mut stack: Vec<Branch> = vec![(None, vec![])];
+        for token in tokens.vec() {
+            let node = match token {
+                Token::Text(text) => DescriptionNode::Text(text.clone()),
+                Token::NewLine => DescriptionNode::NewLine,
+                Token::Indent => DescriptionNode::Indent,
+                Token::Empty => continue,
+                Token::Var(name) => DescriptionNode::Var(name.clone()),
+                Token::Error(text) | Token::Panic(text) => DescriptionNode::Error(text.clone()),
+                Token::TermStart(_, _) | Token::StyleStart(_) => {
+                    stack.push((Some(token), vec![]));
+                    continue;
+                }
+                Token::TermEnd => {
+                    // An unbalanced end is dropped rather than closing the root
+                    if !stack
+                        .iter()
+                        .any(|(t, _)| matches!(t, Some(Token::TermStart(_, _))))
+                    {
+                        continue;
+                    }
+                    // Styles which aren't closed inside the term end with it
+                    while matches!(stack.last(), Some((Some(Token::StyleStart(_)), _))) {
+                        close(&mut stack);
+                    }
+                    let (term, children) = stack.pop().unwrap();
+                    branch_node(term, children)
+                }
+                Token::StyleEnd => {
+                    if matches!(stack.last(), Some((Some(Token::StyleStart(_)), _))) {
+                        close(&mut stack);
+                    }
+                    continue;
+                }
+            };
+            stack.last_mut().unwrap().1.push(node);
+        }
+        // Close the terms and styles left open
+        while stack.len() > 1 {
+            close(&mut stack);
+        }
+        DescriptionTree(stack.pop().unwrap().1)
+    }
+
+    pub fn nodes(&self) -> &Vec<DescriptionNode> {
+        &self.0
+    }
+
+    pub fn into_nodes(self) -> Vec<DescriptionNode> {
+        self.0
+    }
+
+    /// Walks the tree with `renderer`.
+    pub fn render<R: Renderer>(&self, renderer: &mut R) {
+        fn walk<R: Renderer>(nodes: &[DescriptionNode], renderer: &mut R) {
+            for node in nodes {
+                match node {
+                    DescriptionNode::Text(text) => renderer.text(text),
+                    DescriptionNode::NewLine => renderer.new_line(),
+                    DescriptionNode::Indent => renderer.indent(),
+                    DescriptionNode::Var(name) => renderer.var(name),
+                    DescriptionNode::Error(text) => renderer.error(text),
+                    DescriptionNode::Term {
+                        name,
+                        tips,
+                        children,
+                    } => {
+                        renderer.term_start(name, tips.as_ref().map(|s| s.as_str()));
+                        walk(children, renderer);
+                        renderer.term_end(name, tips.as_ref().map(|s| s.as_str()));
+                    }
+                    DescriptionNode::Styled { style, children } => {
+                        renderer.style_start(style);
+                        walk(children, renderer);
+                        renderer.style_end(style);
+                    }
+                }
+            }
+        }
+        walk(&self.0, renderer);
+    }
+
+    pub fn to_html(&self) -> String {
+        self.render_to(HtmlRenderer::default())
+    }
+
+    pub fn to_plain_text(&self) -> String {
+        self.render_to(PlainTextRenderer::default())
+    }
+
+    pub fn to_ansi(&self) -> String {
+        self.render_to(AnsiRenderer::default())
+    }
+
+    pub fn to_markdown(&self) -> String {
+        self.render_to(MarkdownRenderer::default())
+    }
+
+    fn render_to<R: Renderer + Into<String>>(&self, mut renderer: R) -> String {
+        self.render(&mut renderer);
+        renderer.into()
+    }
+}
+
+type Branch<'a> = (Option<&'a Token>, Vec<DescriptionNode>);
+
+fn branch_node(start: Option<&Token>, children: Vec<DescriptionNode>) -> DescriptionNode {
+    match start {
+        Some(Token::TermStart(name, tips)) => DescriptionNode::Term {
+            name: name.clone(),
+            tips: tips.clone(),
+            children,
+        },
+        Some(Token::StyleStart(style)) => DescriptionNode::Styled {
+            style: style.clone(),
+            children,
+        },
+        _ => unreachable!("only term and style starts are pushed"),
+    }
+}
+
+/// Moves the last branch of `stack` into its parent.
+fn close(stack: &mut Vec<Branch>) {
+    let (start, children) = stack.pop().unwrap();
+    stack
+        .last_mut()
+        .unwrap()
+        .1
+        .push(branch_node(start, children));
+}
+
+impl<'a> From<&'a Tokens> for DescriptionTree {
+    fn from(tokens: &'a Tokens) -> Self {
+        DescriptionTree::new(tokens)
+    }
+}
+
+/// An output target of [DescriptionTree::render].
+pub trait Renderer {
+    fn text(&mut self, text: &str);
+    fn new_line(&mut self);
+    fn indent(&mut self);
+    /// An unresolved variable.
+    fn var(&mut self, name: &str);
+    fn error(&mut self, text: &str);
+    fn term_start(&mut self, _name: &str, _tips: Option<&str>) {}
+    fn term_end(&mut self, _name: &str, _tips: Option<&str>) {}
+    fn style_start(&mut self, _style: &Style) {}
+    fn style_end(&mut self, _style: &Style) {}
+}
+
+/// The red, green and blue of a color like `#FB0` or `#FFBB00`.
+fn parse_hex_color(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.strip_prefix('#')?;
+    let digit =
+        |i: usize, len: usize| u8::from_str_radix(hex.get(i * len..(i + 1) * len)?, 16).ok();
+    match hex.len() {
+        3 => Some((digit(0, 1)? * 17, digit(1, 1)? * 17, digit(2, 1)? * 17)),
+        6 | 8 => Some((digit(0, 2)?, digit(1, 2)?, digit(2, 2)?)),
+        _ => None,
+    }
+}
+
+/// Indents with an ideographic space, like the game.
+const INDENT: &str = "\u{3000}";
+
+/// HTML with `term`, `var` and `error` classes, and the explained term in `data-tips`.
+#[derive(Debug, Default)]
+pub struct HtmlRenderer {
+    out: String,
+}
+
+impl HtmlRenderer {
+    fn escape(&mut self, text: &str) {
+        for c in text.chars() {
+            match c {
+                '<' => self.out.push_str("&lt;"),
+                '>' => self.out.push_str("&gt;"),
+                '&' => self.out.push_str("&amp;"),
+                '"' => self.out.push_str("&quot;"),
+                '\'' => self.out.push_str("&#39;"),
+                c => self.out.push(c),
+            }
+        }
+    }
+}
+
+impl Renderer for HtmlRenderer {
+    fn text(&mut self, text: &str) {
+        self.escape(text);
+    }
+
+    fn new_line(&mut self) {
+        self.out.push_str("<br>");
+    }
+
+    fn indent(&mut self) {
+        self.out.push_str("<br>");
+        self.out.push_str(INDENT);
+    }
+
+    fn var(&mut self, name: &str) {
+        self.out.push_str("<span class=\"var\">[");
+        self.escape(name);
+        self.out.push_str("]</span>");
+    }
+
+    fn error(&mut self, text: &str) {
+        self.out.push_str("<span class=\"error\">");
+        self.escape(text);
+        self.out.push_str("</span>");
+    }
+
+    fn term_start(&mut self, _name: &str, tips: Option<&str>) {
+        match tips {
+            Some(tips) => {
+                self.out.push_str("<span class=\"term\" data-tips=\"");
+                self.escape(tips);
+                self.out.push_str("\">");
+            }
+            None => self.out.push_str("<span>"),
+        }
+    }
+
+    fn term_end(&mut self, _name: &str, _tips: Option<&str>) {
+        self.out.push_str("</span>");
+    }
+
+    fn style_start(&mut self, style: &Style) {
+        match style {
+            Style::Bold => self.out.push_str("<b>"),
+            Style::Italic => self.out.push_str("<i>"),
+            Style::Underline => self.out.push_str("<u>"),
+            Style::Strikethrough => self.out.push_str("<s>"),
+            Style::Color(color) => {
+                self.out.push_str("<span style=\"color: ");
+                self.escape(color);
+                self.out.push_str("\">");
+            }
+            // Sizes are in game pixels, which don't fit the surrounding text
+            Style::Size(_) => self.out.push_str("<span>"),
+        }
+    }
+
+    fn style_end(&mut self, style: &Style) {
+        match style {
+            Style::Bold => self.out.push_str("</b>"),
+            Style::Italic => self.out.push_str("</i>"),
+            Style::Underline => self.out.push_str("</u>"),
+            Style::Strikethrough => self.out.push_str("</s>"),
+            Style::Color(_) | Style::Size(_) => self.out.push_str("</span>"),
+        }
+    }
+}
+
+impl From<HtmlRenderer> for String {
+    fn from(r: HtmlRenderer) -> Self {
+        r.out
+    }
+}
+
+/// Text without any markup, as shown in the game.
+#[derive(Debug, Default)]
+pub struct PlainTextRenderer {
+    out: String,
+}
+
+impl Renderer for PlainTextRenderer {
+    fn text(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    fn new_line(&mut self) {
+        self.out.push('\n');
+    }
+
+    fn indent(&mut self) {
+        self.out.push('\n');
+        self.out.push_str(INDENT);
+    }
+
+    fn var(&mut self, name: &str) {
+        let _ = write!(self.out, "[{}]", name);
+    }
+
+    fn error(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+}
+
+impl From<PlainTextRenderer> for String {
+    fn from(r: PlainTextRenderer) -> Self {
+        r.out
+    }
+}
+
+/// Text for terminals, with explained terms underlined in cyan, errors in red and
+/// colors in 24-bit.
+#[derive(Debug, Default)]
+pub struct AnsiRenderer {
+    out: String,
+    /// The escape codes of the terms and styles being rendered, restored after a
+    /// nested one ends.
+    styles: Vec<String>,
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_TERM: &str = "\x1b[4;36m";
+const ANSI_RED: &str = "\x1b[31m";
+
+impl AnsiRenderer {
+    fn styled(&mut self, style: &str, text: &str) {
+        self.out.push_str(style);
+        self.out.push_str(text);
+        self.restore();
+    }
+
+    fn restore(&mut self) {
+        self.out.push_str(ANSI_RESET);
+        for style in &self.styles {
+            self.out.push_str(style);
+        }
+    }
+
+    fn push(&mut self, style: String) {
+        self.out.push_str(&style);
+        self.styles.push(style);
+    }
+
+    fn pop(&mut self) {
+        self.styles.pop();
+        self.restore();
+    }
+}
+
+impl Renderer for AnsiRenderer {
+    fn text(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    fn new_line(&mut self) {
+        self.out.push('\n');
+    }
+
+    fn indent(&mut self) {
+        self.out.push('\n');
+        self.out.push_str(INDENT);
+    }
+
+    fn var(&mut self, name: &str) {
+        self.styled(ANSI_RED, &format!("[{}]", name));
+    }
+
+    fn error(&mut self, text: &str) {
+        self.styled(ANSI_RED, text);
+    }
+
+    fn term_start(&mut self, _name: &str, tips: Option<&str>) {
+        let style = if tips.is_some() { ANSI_TERM } else { "" };
+        self.push(style.to_string());
+    }
+
+    fn term_end(&mut self, _name: &str, _tips: Option<&str>) {
+        self.pop();
+    }
+
+    fn style_start(&mut self, style: &Style) {
+        let code = match style {
+            Style::Bold => "\x1b[1m".to_string(),
+            Style::Italic => "\x1b[3m".to_string(),
+            Style::Underline => "\x1b[4m".to_string(),
+            Style::Strikethrough => "\x1b[9m".to_string(),
+            Style::Color(color) => match parse_hex_color(color) {
+                Some((r, g, b)) => format!("\x1b[38;2;{};{};{}m", r, g, b),
+                None => String::new(),
+            },
+            Style::Size(_) => String::new(),
+        };
+        self.push(code);
+    }
+
+    fn style_end(&mut self, _style: &Style) {
+        self.pop();
+    }
+}
+
+impl From<AnsiRenderer> for String {
+    fn from(r: AnsiRenderer) -> Self {
+        r.out
+    }
+}
+
+/// Markdown with explained terms in bold. Colors, sizes and underlines are dropped.
+#[derive(Debug, Default)]
+pub struct MarkdownRenderer {
+    out: String,
+}
+
+impl MarkdownRenderer {
+    fn escape(&mut self, text: &str) {
+        for c in text.chars() {
+            if "\\`*_[]<>#|~".contains(c) {
+                self.out.push('\\');
+            }
+            self.out.push(c);
+        }
+    }
+}
+
+impl Renderer for MarkdownRenderer {
+    fn text(&mut self, text: &str) {
+        self.escape(text);
+    }
+
+    fn new_line(&mut self) {
+        // A hard line break, as a single newline would join the lines
+        self.out.push_str("\\\n");
+    }
+
+    fn indent(&mut self) {
+        self.out.push_str("\\\n");
+        self.out.push_str(INDENT);
+    }
+
+    fn var(&mut self, name: &str) {
+        let _ = write!(self.out, "`[{}]`", name);
+    }
+
+    fn error(&mut self, text: &str) {
+        self.out.push('`');
+        self.out.push_str(&text.replace('`', ""));
+        self.out.push('`');
+    }
+
+    fn term_start(&mut self, _name: &str, tips: Option<&str>) {
+        if tips.is_some() {
+            self.out.push_str("**");
+        }
+    }
+
+    fn term_end(&mut self, _name: &str, tips: Option<&str>) {
+        if tips.is_some() {
+            self.out.push_str("**");
+        }
+    }
+
+    fn style_start(&mut self, style: &Style) {
+        self.style_end(style);
+    }
+
+    fn style_end(&mut self, style: &Style) {
+        match style {
+            Style::Bold => self.out.push_str("**"),
+            Style::Italic => self.out.push('*'),
+            Style::Strikethrough => self.out.push_str("~~"),
+            Style::Underline | Style::Color(_) | Style::Size(_) => {}
+        }
+    }
+}
+
+impl From<MarkdownRenderer> for String {
+    fn from(r: MarkdownRenderer) -> Self {
+        r.out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tree() -> DescriptionTree {
+        DescriptionTree::new(&Tokens::from_vec(vec![
+            Token::Text("Deals ".to_string()),
+            Token::TermStart("NM-Fire".to_string(), Some("Fire".to_string())),
+            Token::Text("Fire".to_string()),
+            Token::TermEnd,
+            Token::Text(" damage <x2>".to_string()),
+            Token::NewLine,
+            Token::Var("0".to_string()),
+        ]))
+    }
+
+    #[test]
+    fn test_tree() {
+        assert_eq!(
+            tree().nodes()[1],
+            DescriptionNode::Term {
+                name: "NM-Fire".to_string(),
+                tips: Some("Fire".to_string()),
+                children: vec![DescriptionNode::Text("Fire".to_string())],
+            }
+        );
+        let unbalanced = DescriptionTree::new(&Tokens::from_vec(vec![
+            Token::TermEnd,
+            Token::TermStart("NM-Fire".to_string(), None),
+        ]));
+        assert_eq!(unbalanced.nodes().len(), 1);
+
+        // A style crossing the end of a term ends with it
+        let styled = DescriptionTree::new(&Tokens::from_vec(vec![
+            Token::TermStart("NM-Fire".to_string(), None),
+            Token::StyleStart(Style::Bold),
+            Token::Text("Fire".to_string()),
+            Token::TermEnd,
+            Token::StyleEnd,
+            Token::Text("!".to_string()),
+        ]));
+        assert_eq!(styled.to_html(), "<span><b>Fire</b></span>!");
+    }
+
+    #[test]
+    fn test_renderers() {
+        let tree = tree();
+        assert_eq!(tree.to_plain_text(), "Deals Fire damage <x2>\n[0]");
+        assert_eq!(
+            tree.to_html(),
+            "Deals <span class=\"term\" data-tips=\"Fire\">Fire</span> damage &lt;x2&gt;<br>\
+             <span class=\"var\">[0]</span>"
+        );
+        assert_eq!(
+            tree.to_markdown(),
+            "Deals **Fire** damage \\<x2\\>\\\n`[0]`"
+        );
+        let colored = DescriptionTree::new(&Tokens::from_vec(vec![
+            Token::StyleStart(Style::Color("#FB0".to_string())),
+            Token::Text("100".to_string()),
+            Token::StyleEnd,
+        ]));
+        assert_eq!(colored.to_html(), "<span style=\"color: #FB0\">100</span>");
+        assert_eq!(colored.to_ansi(), "\x1b[38;2;255;187;0m100\x1b[0m");
+        assert_eq!(
+            tree.to_ansi(),
+            "Deals \x1b[4;36mFire\x1b[0m damage <x2>\n\x1b[31m[0]\x1b[0m"
+        );
+    }
+}
@@ -3,4 +3,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct GlobalRepository {
     pub rarity_colors: Vec<String>,
+    /// Term ids of the states, whose names are `NM-{id}`.
+    #[serde(default)]
+    pub state_ids: Vec<String>,
+    /// Term ids of the enemies, whose names are `NM-{id}`.
+    #[serde(default)]
+    pub enemy_ids: Vec<String>,
+}
+
+impl GlobalRepository {
+    /// The rarity, from 1, whose color is `color` like `#FB0` or `fb0`.
+    pub fn rarity_of_color(&self, color: &str) -> Option<u8> {
+        let color = color.trim_start_matches('#');
+        self.rarity_colors
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(color))
+            .map(|i| i as u8 + 1)
+    }
 }
@@ -0,0 +1,245 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+#[cfg(feature = "compress")]
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "compress")]
+use artifact::write_artifact;
+use artifact::{read_artifact, ArtifactError};
+use kana::romaji_to_hiragana;
+use tokenizer::{is_fuzzy_match, tokenize};
+use {Database, Field, Queryable, RuneHash, SkillHash};
+
+/// Weight of keywords found in a name.
+pub const NAME_WEIGHT: u8 = 3;
+/// Weight of keywords found in a description or any other text.
+pub const TEXT_WEIGHT: u8 = 1;
+
+/// Inverted index from keywords to keys, built by `gen` and shipped with the database.
+///
+/// Each key remembers the highest weight of the texts the keyword was found in,
+/// so that name matches rank above description matches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchIndex<K: Ord> {
+    keywords: BTreeMap<String, BTreeMap<K, u8>>,
+}
+
+impl<K: Ord> Default for SearchIndex<K> {
+    fn default() -> Self {
+        Self {
+            keywords: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K: Ord + Clone> SearchIndex<K> {
+    pub fn insert(&mut self, key: &K, strings: &[String], weight: u8) {
+        for string in strings {
+            for keyword in tokenize(string) {
+                let w = self
+                    .keywords
+                    .entry(keyword)
+                    .or_default()
+                    .entry(key.clone())
+                    .or_default();
+                *w = (*w).max(weight);
+            }
+        }
+    }
+
+    /// Returns the keys matching every word of `query` with their scores, best first.
+    ///
+    /// A word scores its weight times 3 for an exact keyword, 2 for a prefix, so that
+    /// results update while a word is being typed, and 1 for a typo.
+    /// Romaji words also match their Hiragana reading.
+    pub fn search(&self, query: &str) -> Vec<(&K, u32)> {
+        let mut result: Option<BTreeMap<&K, u32>> = None;
+        for word in query.split_whitespace() {
+            let mut alternatives = vec![tokenize(word)];
+            if let Some(kana) = romaji_to_hiragana(word) {
+                alternatives.push(tokenize(&kana));
+            }
+
+            let mut scores = BTreeMap::new();
+            for keywords in alternatives.iter().filter(|k| !k.is_empty()) {
+                for (key, score) in self.search_all(keywords) {
+                    let s = scores.entry(key).or_insert(0);
+                    *s = score.max(*s);
+                }
+            }
+
+            result = Some(match result {
+                Some(r) => r
+                    .into_iter()
+                    .filter_map(|(key, score)| scores.get(key).map(|s| (key, score + s)))
+                    .collect(),
+                None => scores,
+            });
+        }
+
+        let mut result = result.unwrap_or_default().into_iter().collect::<Vec<_>>();
+        result.sort_by(|(_, a), (_, b)| b.cmp(a));
+        result
+    }
+
+    /// Scores the keys matching all of `keywords`, summing their scores.
+    fn search_all(&self, keywords: &[String]) -> BTreeMap<&K, u32> {
+        let mut result: Option<BTreeMap<&K, u32>> = None;
+        for keyword in keywords {
+            let scores = self.search_keyword(keyword);
+            result = Some(match result {
+                Some(r) => r
+                    .into_iter()
+                    .filter_map(|(key, score)| scores.get(key).map(|s| (key, score + s)))
+                    .collect(),
+                None => scores,
+            });
+        }
+        result.unwrap_or_default()
+    }
+
+    fn search_keyword<'a>(&'a self, keyword: &str) -> BTreeMap<&'a K, u32> {
+        let mut scores = BTreeMap::new();
+        let mut add = |keys: &'a BTreeMap<K, u8>, quality: u32| {
+            for (key, weight) in keys {
+                let s = scores.entry(key).or_insert(0);
+                *s = (u32::from(*weight) * quality).max(*s);
+            }
+        };
+
+        for (k, keys) in self
+            .keywords
+            .range(keyword.to_string()..)
+            .take_while(|(k, _)| k.starts_with(keyword))
+        {
+            add(keys, if k == keyword { 3 } else { 2 });
+        }
+
+        // Typos are rarely in the first character, which narrows the keywords to check
+        if let Some(first) = keyword.chars().next() {
+            for (k, keys) in self
+                .keywords
+                .range(first.to_string()..)
+                .take_while(|(k, _)| k.starts_with(first))
+                .filter(|(k, _)| !k.starts_with(keyword))
+            {
+                if is_fuzzy_match(keyword, k) {
+                    add(keys, 1);
+                }
+            }
+        }
+        scores
+    }
+}
+
+/// Search indexes of a [`Database`], stored next to its chunks as `search-<hash>.msgpack`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchIndexes {
+    pub skill: SearchIndex<SkillHash>,
+    pub rune: SearchIndex<RuneHash>,
+    /// Keyed by the id of the `NM-{id}` term.
+    pub state: SearchIndex<String>,
+    /// Keyed by the id of the `NM-{id}` term.
+    #[serde(default)]
+    pub enemy: SearchIndex<String>,
+    /// Other `NM-{id}` terms, keyed by the id.
+    #[serde(default)]
+    pub term: SearchIndex<String>,
+}
+
+impl SearchIndexes {
+    /// Builds the indexes of `database`. The ids of the terms naming states and
+    /// enemies come from the game data, as the database doesn't keep them.
+    pub fn build(
+        database: &Database,
+        states: &BTreeSet<String>,
+        enemies: &BTreeSet<String>,
+    ) -> Self {
+        let mut indexes = Self::default();
+        for skill in database.skill.iter() {
+            let index = &mut indexes.skill;
+            index.insert(
+                &skill.hash,
+                &skill.query_text(Some(Field::Name)),
+                NAME_WEIGHT,
+            );
+            index.insert(
+                &skill.hash,
+                &skill.query_text(Some(Field::Description)),
+                TEXT_WEIGHT,
+            );
+        }
+        for rune in database.rune.iter() {
+            let index = &mut indexes.rune;
+            index.insert(&rune.hash, &rune.query_text(Some(Field::Name)), NAME_WEIGHT);
+            index.insert(
+                &rune.hash,
+                &rune.query_text(Some(Field::Description)),
+                TEXT_WEIGHT,
+            );
+        }
+        for key in database.term.keys() {
+            if let Some(id) = key.strip_prefix("NM-") {
+                let index = if states.contains(id) {
+                    &mut indexes.state
+                } else if enemies.contains(id) {
+                    &mut indexes.enemy
+                } else {
+                    &mut indexes.term
+                };
+                let id = id.to_string();
+                index.insert(&id, &[database.term.get_str(key)], NAME_WEIGHT);
+                if let Some(tips) = database.term.try_get(&format!("DC-{}", id)) {
+                    index.insert(&id, &[format!("{}", tips)], TEXT_WEIGHT);
+                }
+            }
+        }
+        indexes
+    }
+
+    pub fn read<R: Read>(read: R) -> Result<Self, ArtifactError> {
+        read_artifact(read).map(|a| a.value)
+    }
+
+    /// Writes the indexes of the database of game version `version`.
+    #[cfg(feature = "compress")]
+    pub fn write<W: Write>(&self, version: &str, write: &mut W) -> std::io::Result<()> {
+        write_artifact(write, version, self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        let mut index = SearchIndex::default();
+        index.insert(&1, &["Fire Ball".to_string()], NAME_WEIGHT);
+        index.insert(&2, &["Fire Wall".to_string()], NAME_WEIGHT);
+        index.insert(&3, &["ファイアボール".to_string()], NAME_WEIGHT);
+        index.insert(&4, &["Ignites the wall".to_string()], TEXT_WEIGHT);
+
+        let keys = |query| {
+            index
+                .search(query)
+                .into_iter()
+                .map(|(k, _)| *k)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(keys("fire"), vec![1, 2]);
+        assert_eq!(keys("fire wa"), vec![2]);
+        assert_eq!(keys("wall"), vec![2, 4]);
+        assert_eq!(keys("ice"), Vec::<i32>::new());
+        assert_eq!(keys(""), Vec::<i32>::new());
+        assert_eq!(keys("ボール"), vec![3]);
+        assert_eq!(keys("ぼ"), vec![3]);
+        assert_eq!(keys("faiabo"), vec![3]);
+        assert_eq!(keys("fier"), vec![1, 2]);
+        assert_eq!(index.search("fire")[0].1, 9);
+        assert_eq!(index.search("fir")[0].1, 6);
+        assert_eq!(index.search("fier")[0].1, 3);
+    }
+}
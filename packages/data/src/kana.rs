@@ -0,0 +1,137 @@
+//! Normalization between Katakana, Hiragana and romaji, so that Japanese names can be
+//! searched in either script or typed with a Latin keyboard.
+
+/// Converts a Katakana character to Hiragana, leaving any other character as is.
+pub fn to_hiragana(c: char) -> char {
+    match c {
+        '\u{30a1}'..='\u{30f6}' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+#[rustfmt::skip]
+const ROMAJI: &[(&str, &str)] = &[
+    ("kya", "きゃ"), ("kyu", "きゅ"), ("kyo", "きょ"),
+    ("gya", "ぎゃ"), ("gyu", "ぎゅ"), ("gyo", "ぎょ"),
+    ("sha", "しゃ"), ("shu", "しゅ"), ("sho", "しょ"), ("she", "しぇ"), ("shi", "し"),
+    ("sya", "しゃ"), ("syu", "しゅ"), ("syo", "しょ"),
+    ("cha", "ちゃ"), ("chu", "ちゅ"), ("cho", "ちょ"), ("che", "ちぇ"), ("chi", "ち"),
+    ("tya", "ちゃ"), ("tyu", "ちゅ"), ("tyo", "ちょ"),
+    ("jya", "じゃ"), ("jyu", "じゅ"), ("jyo", "じょ"),
+    ("zya", "じゃ"), ("zyu", "じゅ"), ("zyo", "じょ"),
+    ("nya", "にゃ"), ("nyu", "にゅ"), ("nyo", "にょ"),
+    ("hya", "ひゃ"), ("hyu", "ひゅ"), ("hyo", "ひょ"),
+    ("bya", "びゃ"), ("byu", "びゅ"), ("byo", "びょ"),
+    ("pya", "ぴゃ"), ("pyu", "ぴゅ"), ("pyo", "ぴょ"),
+    ("mya", "みゃ"), ("myu", "みゅ"), ("myo", "みょ"),
+    ("rya", "りゃ"), ("ryu", "りゅ"), ("ryo", "りょ"),
+    ("tsu", "つ"),
+    ("ja", "じゃ"), ("ju", "じゅ"), ("jo", "じょ"), ("je", "じぇ"), ("ji", "じ"),
+    ("fa", "ふぁ"), ("fi", "ふぃ"), ("fu", "ふ"), ("fe", "ふぇ"), ("fo", "ふぉ"),
+    ("va", "ゔぁ"), ("vi", "ゔぃ"), ("vu", "ゔ"), ("ve", "ゔぇ"), ("vo", "ゔぉ"),
+    ("ka", "か"), ("ki", "き"), ("ku", "く"), ("ke", "け"), ("ko", "こ"),
+    ("ga", "が"), ("gi", "ぎ"), ("gu", "ぐ"), ("ge", "げ"), ("go", "ご"),
+    ("sa", "さ"), ("si", "し"), ("su", "す"), ("se", "せ"), ("so", "そ"),
+    ("za", "ざ"), ("zi", "じ"), ("zu", "ず"), ("ze", "ぜ"), ("zo", "ぞ"),
+    ("ta", "た"), ("ti", "ち"), ("tu", "つ"), ("te", "て"), ("to", "と"),
+    ("da", "だ"), ("di", "ぢ"), ("du", "づ"), ("de", "で"), ("do", "ど"),
+    ("na", "な"), ("ni", "に"), ("nu", "ぬ"), ("ne", "ね"), ("no", "の"),
+    ("ha", "は"), ("hi", "ひ"), ("hu", "ふ"), ("he", "へ"), ("ho", "ほ"),
+    ("ba", "ば"), ("bi", "び"), ("bu", "ぶ"), ("be", "べ"), ("bo", "ぼ"),
+    ("pa", "ぱ"), ("pi", "ぴ"), ("pu", "ぷ"), ("pe", "ぺ"), ("po", "ぽ"),
+    ("ma", "ま"), ("mi", "み"), ("mu", "む"), ("me", "め"), ("mo", "も"),
+    ("ya", "や"), ("yu", "ゆ"), ("yo", "よ"),
+    ("ra", "ら"), ("ri", "り"), ("ru", "る"), ("re", "れ"), ("ro", "ろ"),
+    ("wa", "わ"), ("wi", "うぃ"), ("we", "うぇ"), ("wo", "を"),
+    ("xa", "ぁ"), ("xi", "ぃ"), ("xu", "ぅ"), ("xe", "ぇ"), ("xo", "ぉ"),
+    ("a", "あ"), ("i", "い"), ("u", "う"), ("e", "え"), ("o", "お"),
+];
+
+/// Converts a romaji word like `faia` or `kouzan` to Hiragana.
+///
+/// Both Hepburn and Kunrei spellings are accepted, a doubled consonant becomes `っ`
+/// and `-` becomes `ー`. A trailing incomplete syllable is dropped, since the word may
+/// still be being typed. Returns `None` if `word` is not romaji.
+pub fn romaji_to_hiragana(word: &str) -> Option<String> {
+    let word = word.to_lowercase();
+    let mut rest = word.as_str();
+    let mut kana = String::new();
+
+    while !rest.is_empty() {
+        if let Some((romaji, hiragana)) = ROMAJI.iter().find(|(r, _)| rest.starts_with(r)) {
+            kana.push_str(hiragana);
+            rest = &rest[romaji.len()..];
+            continue;
+        }
+
+        let mut chars = rest.chars();
+        let c = chars.next()?;
+        let next = chars.next();
+        match (c, next) {
+            ('-', _) => kana.push('ー'),
+            ('n', Some('n')) => {
+                kana.push('ん');
+                // `kanna` is かんな, but `kannda` is かんだ
+                if !rest[2..].starts_with(['a', 'i', 'u', 'e', 'o', 'y']) {
+                    rest = &rest[1..];
+                }
+            }
+            ('n', Some(n)) if !"aiueoy".contains(n) => kana.push('ん'),
+            ('n', None) => kana.push('ん'),
+            (c, Some(n)) if c == n && c.is_ascii_lowercase() && !"aiueo".contains(c) => {
+                kana.push('っ')
+            }
+            (c, _) if c.is_ascii_lowercase() && is_incomplete(rest) => break,
+            _ => return None,
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+
+    if kana.is_empty() {
+        None
+    } else {
+        Some(kana)
+    }
+}
+
+/// Whether `rest` is the beginning of a syllable, like `ky` or `ts`.
+fn is_incomplete(rest: &str) -> bool {
+    ROMAJI
+        .iter()
+        .any(|(romaji, _)| romaji.len() > rest.len() && romaji.starts_with(rest))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_hiragana() {
+        assert_eq!(
+            "ファイアボール"
+                .chars()
+                .map(to_hiragana)
+                .collect::<String>(),
+            "ふぁいあぼーる"
+        );
+        assert_eq!(to_hiragana('炎'), '炎');
+    }
+
+    #[test]
+    fn test_romaji_to_hiragana() {
+        assert_eq!(
+            romaji_to_hiragana("faiaboru"),
+            Some("ふぁいあぼる".to_string())
+        );
+        assert_eq!(romaji_to_hiragana("fa-"), Some("ふぁー".to_string()));
+        assert_eq!(romaji_to_hiragana("shippu"), Some("しっぷ".to_string()));
+        assert_eq!(romaji_to_hiragana("kanna"), Some("かんな".to_string()));
+        assert_eq!(romaji_to_hiragana("hannda"), Some("はんだ".to_string()));
+        assert_eq!(romaji_to_hiragana("kenja"), Some("けんじゃ".to_string()));
+        assert_eq!(romaji_to_hiragana("ken"), Some("けん".to_string()));
+        assert_eq!(romaji_to_hiragana("kaky"), Some("か".to_string()));
+        assert_eq!(romaji_to_hiragana("k"), None);
+        assert_eq!(romaji_to_hiragana("10"), None);
+        assert_eq!(romaji_to_hiragana("fire"), Some("ふぃれ".to_string()));
+    }
+}
@@ -1,16 +1,30 @@
+#[cfg(feature = "compress")]
+extern crate brotli;
+extern crate brotli_decompressor;
+extern crate crc32fast;
 extern crate rmp_serde;
 extern crate serde;
 extern crate strum;
 
+pub use artifact::*;
+pub use chunk::*;
 pub use database::*;
 pub use global::*;
+pub use index::*;
+pub use query::*;
 pub use rune::*;
 pub use search::*;
 pub use skill::*;
 pub use sprite::*;
 
+mod artifact;
+mod chunk;
 mod database;
+pub mod description;
 mod global;
+mod index;
+pub mod kana;
+mod query;
 mod rune;
 mod search;
 pub mod skill;
@@ -18,6 +32,7 @@ mod sprite;
 pub mod state;
 pub mod term;
 pub mod token;
+pub mod tokenizer;
 
 pub const LANGUAGES: [&str; 12] = [
     "ja", "en", "fr", "ko", "zh-CN", "zh-TW", "de", "es", "it", "ru", "pt", "pt-BR",
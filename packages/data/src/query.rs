@@ -0,0 +1,736 @@
+use std::collections::BTreeMap;
+
+use kana::romaji_to_hiragana;
+use tokenizer::tokenize;
+use {Rune, SearchIndex, Skill};
+
+/// A field which can be named in a query, like `name:` or `cd<=`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Name,
+    Description,
+    Category,
+    Trigger,
+    Rarity,
+    Cooldown,
+}
+
+impl Field {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "name" => Some(Field::Name),
+            "desc" | "description" => Some(Field::Description),
+            "category" | "cat" => Some(Field::Category),
+            "trigger" => Some(Field::Trigger),
+            "rarity" => Some(Field::Rarity),
+            "cd" | "cooldown" => Some(Field::Cooldown),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Op {
+    fn eval(&self, lhs: i32, rhs: i32) -> bool {
+        match self {
+            Op::Eq => lhs == rhs,
+            Op::Lt => lhs < rhs,
+            Op::Le => lhs <= rhs,
+            Op::Gt => lhs > rhs,
+            Op::Ge => lhs >= rhs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// Words in `field`, or anywhere when `field` is `None`.
+    /// A phrase must appear as is, other words match by prefix.
+    Text {
+        field: Option<Field>,
+        value: String,
+        phrase: bool,
+    },
+    Compare {
+        field: Field,
+        op: Op,
+        value: i32,
+    },
+    /// Clauses joined by `OR`, of which one must match.
+    Any(Vec<Clause>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clause {
+    pub negated: bool,
+    pub condition: Condition,
+}
+
+/// A parsed search query. Every clause must match.
+///
+/// ```text
+/// fire -name:ball "deal damage" category:attack rarity>=4 cd<=3 trigger:passive
+/// ```
+///
+/// `OR` between two clauses matches either, and binds tighter than the other clauses:
+/// `fire ball OR wall` is `fire (ball OR wall)`.
+/// A leading `OR` is searched as a word. A trailing `OR` is ignored, so results don't
+/// change while the next alternative is being typed.
+///
+/// Unknown fields and malformed comparisons are searched as plain text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    pub clauses: Vec<Clause>,
+}
+
+/// Fields of an item which a [`Query`] can be evaluated against.
+pub trait Queryable {
+    fn query_text(&self, field: Option<Field>) -> Vec<String>;
+    fn query_keywords(&self, field: Field) -> Vec<String>;
+    fn query_numbers(&self, field: Field) -> Vec<i32>;
+}
+
+impl Query {
+    pub fn parse(s: &str) -> Self {
+        let mut clauses: Vec<Clause> = vec![];
+        let mut or = false;
+        let mut chars = s.chars().peekable();
+        loop {
+            while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                chars.next();
+            }
+            if chars.peek().is_none() {
+                break;
+            }
+
+            let negated = chars.peek() == Some(&'-');
+            if negated {
+                chars.next();
+            }
+
+            let mut word = String::new();
+            let mut phrase = None;
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                chars.next();
+                if c == '"' {
+                    let mut p = String::new();
+                    for c in chars.by_ref() {
+                        if c == '"' {
+                            break;
+                        }
+                        p.push(c);
+                    }
+                    phrase = Some(p);
+                    break;
+                }
+                word.push(c);
+            }
+
+            // Joins the previous clause with the next one. Without a next clause, it's dropped.
+            if word == "OR" && !negated && phrase.is_none() && !clauses.is_empty() {
+                or = true;
+                continue;
+            }
+            if let Some(condition) = Self::parse_condition(&word, phrase) {
+                let clause = Clause { negated, condition };
+                match clauses.pop() {
+                    Some(Clause {
+                        negated: false,
+                        condition: Condition::Any(mut any),
+                    }) if or => {
+                        any.push(clause);
+                        clauses.push(Clause {
+                            negated: false,
+                            condition: Condition::Any(any),
+                        });
+                    }
+                    Some(last) if or => clauses.push(Clause {
+                        negated: false,
+                        condition: Condition::Any(vec![last, clause]),
+                    }),
+                    Some(last) => clauses.extend([last, clause]),
+                    None => clauses.push(clause),
+                }
+                or = false;
+            }
+        }
+        Query { clauses }
+    }
+
+    fn parse_condition(word: &str, phrase: Option<String>) -> Option<Condition> {
+        let split = word
+            .find([':', '<', '>', '='])
+            .and_then(|i| Field::parse(&word[..i]).map(|field| (field, &word[i..])));
+
+        let text = |field: Option<Field>, value: &str| {
+            let (value, is_phrase) = match &phrase {
+                Some(p) => (format!("{}{}", value, p), true),
+                None => (value.to_string(), false),
+            };
+            if value.is_empty() {
+                None
+            } else {
+                Some(Condition::Text {
+                    field,
+                    value,
+                    phrase: is_phrase,
+                })
+            }
+        };
+
+        match split {
+            Some((field, rest)) => {
+                let (op, value) = [
+                    ("<=", Op::Le),
+                    (">=", Op::Ge),
+                    ("<", Op::Lt),
+                    (">", Op::Gt),
+                    ("=", Op::Eq),
+                    (":", Op::Eq),
+                ]
+                .iter()
+                .find(|(prefix, _)| rest.starts_with(prefix))
+                .map(|(prefix, op)| (*op, &rest[prefix.len()..]))?;
+
+                match field {
+                    Field::Rarity | Field::Cooldown => match value.parse::<i32>() {
+                        Ok(value) if phrase.is_none() => {
+                            Some(Condition::Compare { field, op, value })
+                        }
+                        _ => text(None, word),
+                    },
+                    _ if op == Op::Eq && rest.starts_with(':') => text(Some(field), value),
+                    _ => text(None, word),
+                }
+            }
+            None => text(None, word),
+        }
+    }
+
+    /// Returns the keys of `items` matching the query, best first.
+    /// Plain words are looked up and scored in `index`, everything else is checked
+    /// against the item. Keys with equal scores keep the order of `items`.
+    pub fn evaluate<'a, K, V, I>(&self, index: &SearchIndex<K>, items: I) -> Vec<&'a K>
+    where
+        K: Ord + Clone + 'a,
+        V: Queryable + 'a,
+        I: Iterator<Item = (&'a K, &'a V)>,
+    {
+        let mut hits = BTreeMap::new();
+        for clause in &self.clauses {
+            clause.search(index, &mut hits);
+        }
+
+        let mut results = items
+            .filter_map(|(key, item)| {
+                let mut score = 0;
+                for clause in &self.clauses {
+                    score += clause.score(key, item, &hits)?;
+                }
+                Some((key, score))
+            })
+            .collect::<Vec<_>>();
+        results.sort_by(|(_, a), (_, b)| b.cmp(a));
+        results.into_iter().map(|(key, _)| key).collect()
+    }
+
+    /// Returns the words and phrases to highlight in names and descriptions of results,
+    /// including the Hiragana reading of romaji words.
+    pub fn highlight_terms(&self) -> Vec<String> {
+        let mut terms = vec![];
+        let alternatives = self
+            .clauses
+            .iter()
+            .flat_map(|clause| match &clause.condition {
+                Condition::Any(any) if !clause.negated => any.iter().collect(),
+                _ => vec![clause],
+            });
+        for clause in alternatives.filter(|c| !c.negated) {
+            if let Condition::Text {
+                field: None | Some(Field::Name) | Some(Field::Description),
+                value,
+                phrase,
+            } = &clause.condition
+            {
+                if *phrase {
+                    terms.push(value.clone());
+                    continue;
+                }
+                for word in value.split_whitespace() {
+                    terms.push(word.to_string());
+                    terms.extend(romaji_to_hiragana(word));
+                }
+            }
+        }
+        terms
+    }
+}
+
+/// Index hits of the plain words of a query, by word.
+type Hits<K> = BTreeMap<String, BTreeMap<K, u32>>;
+
+impl Clause {
+    /// Looks up the plain words of the clause in `index`.
+    fn search<K: Ord + Clone>(&self, index: &SearchIndex<K>, hits: &mut Hits<K>) {
+        match &self.condition {
+            Condition::Text {
+                field: None,
+                value,
+                phrase: false,
+            } => {
+                hits.entry(value.clone()).or_insert_with(|| {
+                    index
+                        .search(value)
+                        .into_iter()
+                        .map(|(key, score)| (key.clone(), score))
+                        .collect()
+                });
+            }
+            Condition::Any(any) => any.iter().for_each(|c| c.search(index, hits)),
+            _ => (),
+        }
+    }
+
+    /// The score of the item when it satisfies the clause.
+    fn score<K: Ord, V: Queryable>(&self, key: &K, item: &V, hits: &Hits<K>) -> Option<u32> {
+        let hit = match &self.condition {
+            Condition::Text {
+                field: None,
+                value,
+                phrase: false,
+            } => hits.get(value).and_then(|h| h.get(key)).copied(),
+            Condition::Any(any) => any.iter().filter_map(|c| c.score(key, item, hits)).max(),
+            condition => condition.matches(item).then_some(0),
+        };
+        match (hit, self.negated) {
+            (Some(s), false) => Some(s),
+            (None, true) => Some(0),
+            _ => None,
+        }
+    }
+}
+
+impl Condition {
+    pub fn matches<V: Queryable>(&self, item: &V) -> bool {
+        match self {
+            Condition::Any(any) => any.iter().any(|c| c.condition.matches(item) != c.negated),
+            Condition::Text {
+                field: Some(field @ Field::Category),
+                value,
+                ..
+            }
+            | Condition::Text {
+                field: Some(field @ Field::Trigger),
+                value,
+                ..
+            } => {
+                let value = value.to_lowercase();
+                item.query_keywords(*field)
+                    .iter()
+                    .any(|k| k.starts_with(&value))
+            }
+            Condition::Text {
+                field,
+                value,
+                phrase: true,
+            } => {
+                let value = value.to_lowercase();
+                item.query_text(*field)
+                    .iter()
+                    .any(|t| t.to_lowercase().contains(&value))
+            }
+            Condition::Text {
+                field,
+                value,
+                phrase: false,
+            } => {
+                let keywords = item
+                    .query_text(*field)
+                    .iter()
+                    .flat_map(|t| tokenize(t))
+                    .collect::<Vec<_>>();
+                tokenize(value)
+                    .iter()
+                    .all(|v| keywords.iter().any(|k| k.starts_with(v.as_str())))
+            }
+            Condition::Compare { field, op, value } => item
+                .query_numbers(*field)
+                .iter()
+                .any(|n| op.eval(*n, *value)),
+        }
+    }
+}
+
+impl Queryable for Skill {
+    fn query_text(&self, field: Option<Field>) -> Vec<String> {
+        match field {
+            None => self.search_strings(),
+            Some(Field::Name) => {
+                let mut names = vec![self.name.clone()];
+                names.extend(self.modes.iter().map(|mode| mode.name.clone()));
+                names
+            }
+            Some(Field::Description) => self
+                .modes
+                .iter()
+                .map(|mode| format!("{}", mode.format()))
+                .collect(),
+            Some(_) => vec![],
+        }
+    }
+
+    fn query_keywords(&self, field: Field) -> Vec<String> {
+        match field {
+            Field::Category => vec![format!("{:?}", self.category).to_lowercase()],
+            Field::Trigger => self
+                .modes
+                .iter()
+                .flat_map(|mode| mode.acts.iter())
+                .map(|act| format!("{:?}", act.act_trigger).to_lowercase())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn query_numbers(&self, field: Field) -> Vec<i32> {
+        match field {
+            Field::Rarity => vec![self.rarity as i32],
+            Field::Cooldown => self.modes.iter().map(|mode| mode.cooldown as i32).collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl Queryable for Rune {
+    fn query_text(&self, field: Option<Field>) -> Vec<String> {
+        match field {
+            None => self.search_strings(),
+            Some(Field::Name) => vec![self.name.clone()],
+            Some(Field::Description) => vec![format!("{}", self.format())],
+            Some(_) => vec![],
+        }
+    }
+
+    fn query_keywords(&self, _field: Field) -> Vec<String> {
+        vec![]
+    }
+
+    fn query_numbers(&self, field: Field) -> Vec<i32> {
+        match field {
+            Field::Rarity => vec![self.rarity as i32],
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+    use std::rc::Rc;
+
+    use super::*;
+    use skill::{Act, ActTrigger, SkillCategory, SkillMode, SkillRepository};
+    use token::{Token, Tokens};
+    use {Database, RuneRepository, SearchIndexes, Sprite};
+
+    fn text(field: Option<Field>, value: &str, phrase: bool) -> Condition {
+        Condition::Text {
+            field,
+            value: value.to_string(),
+            phrase,
+        }
+    }
+
+    fn clause(negated: bool, condition: Condition) -> Clause {
+        Clause { negated, condition }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Query::parse("  ").clauses, vec![]);
+        assert_eq!(
+            Query::parse(r#"fire -name:ball "deal damage" desc:"to all""#).clauses,
+            vec![
+                clause(false, text(None, "fire", false)),
+                clause(true, text(Some(Field::Name), "ball", false)),
+                clause(false, text(None, "deal damage", true)),
+                clause(false, text(Some(Field::Description), "to all", true)),
+            ]
+        );
+        assert_eq!(
+            Query::parse("category:attack rarity>=4 cd<=3 trigger:passive cd:2").clauses,
+            vec![
+                clause(false, text(Some(Field::Category), "attack", false)),
+                clause(
+                    false,
+                    Condition::Compare {
+                        field: Field::Rarity,
+                        op: Op::Ge,
+                        value: 4
+                    }
+                ),
+                clause(
+                    false,
+                    Condition::Compare {
+                        field: Field::Cooldown,
+                        op: Op::Le,
+                        value: 3
+                    }
+                ),
+                clause(false, text(Some(Field::Trigger), "passive", false)),
+                clause(
+                    false,
+                    Condition::Compare {
+                        field: Field::Cooldown,
+                        op: Op::Eq,
+                        value: 2
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            Query::parse("foo:bar cd<=x name>3").clauses,
+            vec![
+                clause(false, text(None, "foo:bar", false)),
+                clause(false, text(None, "cd<=x", false)),
+                clause(false, text(None, "name>3", false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlight_terms() {
+        assert_eq!(
+            Query::parse(r#"faia -ball name:wall "deal damage" rarity>=4"#).highlight_terms(),
+            vec!["faia", "ふぁいあ", "wall", "deal damage"]
+        );
+        assert_eq!(
+            Query::parse("ball OR -wall OR desc:ice").highlight_terms(),
+            vec!["ball", "ice"]
+        );
+    }
+
+    fn tokens(text: &str) -> Tokens {
+        Tokens::from_vec(vec![Token::Text(text.to_string())])
+    }
+
+    fn sprite() -> Sprite {
+        Sprite {
+            index: 0,
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+        }
+    }
+
+    fn skill(
+        hash: u16,
+        name: &str,
+        category: SkillCategory,
+        cooldown: i8,
+        trigger: ActTrigger,
+        description: &str,
+    ) -> Skill {
+        Skill {
+            hash,
+            id: format!("SK{}", hash),
+            modes: vec![SkillMode {
+                id: format!("SM{}", hash),
+                icon: sprite(),
+                is_alt: false,
+                is_brave: false,
+                use_num: 0,
+                use_brave: 0,
+                cooldown,
+                use_init: false,
+                is_quick: false,
+                acts: vec![Act {
+                    id: format!("SA{}", hash),
+                    act_trigger: trigger,
+                    nodes: vec![],
+                    description: Tokens::new(),
+                }],
+                name: name.to_string(),
+                description_head: tokens(description),
+                description_body: None,
+                description_tail: Tokens::new(),
+                poss_num: 1,
+            }],
+            category,
+            rarity: hash as i8,
+            in_dictionary: true,
+            is_free: false,
+            name: name.to_string(),
+        }
+    }
+
+    fn rune(hash: u16, name: &str, rarity: i8, description: &str) -> Rune {
+        Rune {
+            hash,
+            id: format!("RN{}", hash),
+            order: hash as i16,
+            icon: sprite(),
+            rarity,
+            name: name.to_string(),
+            description: tokens(description),
+        }
+    }
+
+    fn database() -> Database {
+        Database {
+            skill: Rc::new(SkillRepository::from_vec(vec![
+                skill(
+                    1,
+                    "Fire Ball",
+                    SkillCategory::Attack,
+                    2,
+                    ActTrigger::OnUse,
+                    "Deals fire damage.",
+                ),
+                skill(
+                    2,
+                    "Fire Wall",
+                    SkillCategory::Survive,
+                    4,
+                    ActTrigger::TurnStart,
+                    "Blocks the next attack.",
+                ),
+                skill(
+                    3,
+                    "Ice Lance",
+                    SkillCategory::Attack,
+                    3,
+                    ActTrigger::OnUse,
+                    "Deals damage to all enemies. Burns fire skills.",
+                ),
+                skill(
+                    4,
+                    "Aura",
+                    SkillCategory::Support,
+                    0,
+                    ActTrigger::Passive,
+                    "Heals each turn.",
+                ),
+            ])),
+            rune: Rc::new(RuneRepository::from_vec(vec![
+                rune(1, "Ruby", 5, "Fire skills deal more damage."),
+                rune(2, "Sapphire", 3, "Ice skills cool down faster."),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    fn skills(query: &str) -> Vec<u16> {
+        let database = database();
+        let indexes = SearchIndexes::build(&database, &BTreeSet::new(), &BTreeSet::new());
+        Query::parse(query)
+            .evaluate(
+                &indexes.skill,
+                database.skill.iter().map(|skill| (&skill.hash, skill)),
+            )
+            .into_iter()
+            .copied()
+            .collect()
+    }
+
+    fn runes(query: &str) -> Vec<u16> {
+        let database = database();
+        let indexes = SearchIndexes::build(&database, &BTreeSet::new(), &BTreeSet::new());
+        Query::parse(query)
+            .evaluate(
+                &indexes.rune,
+                database.rune.iter().map(|rune| (&rune.hash, rune)),
+            )
+            .into_iter()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluate_fields() {
+        // Names rank above descriptions
+        assert_eq!(skills("fire"), vec![1, 2, 3]);
+        assert_eq!(skills("name:fire"), vec![1, 2]);
+        assert_eq!(skills("desc:fire"), vec![1, 3]);
+        assert_eq!(skills(r#"desc:"deals damage""#), vec![3]);
+        assert_eq!(skills("category:attack"), vec![1, 3]);
+        assert_eq!(skills("cat:sup"), vec![4]);
+        assert_eq!(skills("trigger:passive"), vec![4]);
+        assert_eq!(skills("trigger:turnstart fire"), vec![2]);
+        assert_eq!(runes("desc:ice"), vec![2]);
+        assert_eq!(runes("category:attack"), Vec::<u16>::new());
+    }
+
+    #[test]
+    fn test_evaluate_numbers() {
+        assert_eq!(skills("cd<=2"), vec![1, 4]);
+        assert_eq!(skills("cd<2"), vec![4]);
+        assert_eq!(skills("cd>3"), vec![2]);
+        assert_eq!(skills("cd>=3"), vec![2, 3]);
+        assert_eq!(skills("cd:3"), vec![3]);
+        assert_eq!(skills("rarity>=3 category:attack"), vec![3]);
+        assert_eq!(runes("rarity>4"), vec![1]);
+        assert_eq!(runes("cd<=2"), Vec::<u16>::new());
+    }
+
+    #[test]
+    fn test_evaluate_negation() {
+        assert_eq!(skills("fire -wall"), vec![1, 3]);
+        assert_eq!(skills("-name:fire"), vec![3, 4]);
+        assert_eq!(skills("-category:attack -cd>3"), vec![4]);
+        assert_eq!(runes(r#"-"more damage""#), vec![2]);
+    }
+
+    #[test]
+    fn test_evaluate_or() {
+        assert_eq!(
+            Query::parse("fire ball OR -wall").clauses,
+            vec![
+                clause(false, text(None, "fire", false)),
+                clause(
+                    false,
+                    Condition::Any(vec![
+                        clause(false, text(None, "ball", false)),
+                        clause(true, text(None, "wall", false)),
+                    ])
+                ),
+            ]
+        );
+        assert_eq!(skills("ball OR wall"), vec![1, 2]);
+        assert_eq!(skills("ball OR wall OR aura"), vec![1, 2, 4]);
+        assert_eq!(skills("fire ball OR cd>3"), vec![1, 2]);
+        assert_eq!(skills("category:support OR cd:3"), vec![3, 4]);
+        assert_eq!(skills("name:ice OR -category:attack"), vec![2, 3, 4]);
+        assert_eq!(runes("ruby OR sapphire"), vec![1, 2]);
+        // A leading `OR` is a word
+        assert_eq!(
+            Query::parse("OR").clauses,
+            vec![clause(false, text(None, "OR", false))]
+        );
+        assert_eq!(
+            Query::parse("OR fire").clauses,
+            vec![
+                clause(false, text(None, "OR", false)),
+                clause(false, text(None, "fire", false)),
+            ]
+        );
+        // A trailing `OR` is ignored
+        assert_eq!(
+            Query::parse("fire OR ").clauses,
+            vec![clause(false, text(None, "fire", false))]
+        );
+        assert_eq!(skills("ball OR"), skills("ball"));
+    }
+}
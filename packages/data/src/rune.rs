@@ -23,6 +23,11 @@ impl Rune {
     pub fn format(&self) -> Tokens {
         self.description.clone()
     }
+
+    /// Texts to index for search: the name and the description.
+    pub fn search_strings(&self) -> Vec<String> {
+        vec![self.name.clone(), format!("{}", self.format())]
+    }
 }
 
 impl<M: SearchMarker, N: Search<M>> SearchIndexable<RuneHash, M, N> for Rune {
@@ -31,7 +36,7 @@ impl<M: SearchMarker, N: Search<M>> SearchIndexable<RuneHash, M, N> for Rune {
     }
 
     fn strings(&self) -> Vec<String> {
-        vec![self.name.clone(), format!("{}", self.format())]
+        self.search_strings()
     }
 }
 
@@ -56,6 +61,10 @@ impl RuneRepository {
     pub fn iter(&self) -> impl Iterator<Item = &Rune> {
         self.order.iter().map(move |k| &self.inner[k])
     }
+
+    pub fn find(&self, name: &str) -> Option<&Rune> {
+        self.inner.values().find(|rune| rune.name == name)
+    }
 }
 
 impl Repository<RuneHash, Rune> for RuneRepository {
@@ -1,5 +1,7 @@
 use std::hash::Hash;
 
+use Queryable;
+
 pub trait SearchIndexable<K, M: SearchMarker, N: Search<M>> {
     fn id(&self) -> K;
     fn strings(&self) -> Vec<String>;
@@ -7,7 +9,7 @@ pub trait SearchIndexable<K, M: SearchMarker, N: Search<M>> {
 
 pub trait Search<M: SearchMarker>: Sized {
     type Key: Ord + Clone + Hash;
-    type Item: SearchIndexable<Self::Key, M, Self>;
+    type Item: SearchIndexable<Self::Key, M, Self> + Queryable;
     type Repository: Repository<Self::Key, Self::Item>;
     type Marker: SearchMarker;
 }
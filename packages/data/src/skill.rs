@@ -109,13 +109,18 @@ impl<M: SearchMarker, N: Search<M>> SearchIndexable<SkillHash, M, N> for Skill {
     }
 
     fn strings(&self) -> Vec<String> {
+        self.search_strings()
+    }
+}
+
+impl Skill {
+    /// Texts to index for search: the name, and the name and description of each mode.
+    pub fn search_strings(&self) -> Vec<String> {
         let mut strings = vec![self.name.clone()];
-        strings.push(
-            self.modes
-                .iter()
-                .flat_map(|mode| vec![mode.name.clone(), format!("{}", mode.format())])
-                .collect(),
-        );
+        for mode in &self.modes {
+            strings.push(mode.name.clone());
+            strings.push(format!("{}", mode.format()));
+        }
         strings
     }
 }
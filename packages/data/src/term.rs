@@ -1,10 +1,12 @@
 // TODO: move to gen
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::ops::{Deref, DerefMut};
 
 use serde::{Deserialize, Serialize};
 
 use token::{Token, Tokens};
+use tokenizer::{is_fuzzy_match, tokenize};
+use Database;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Term {
@@ -56,6 +58,102 @@ impl<'a> TermRepository {
     }
 }
 
+impl Database {
+    /// The game keywords explained by a tooltip in the skill and rune descriptions,
+    /// sorted by name.
+    pub fn glossary(&self) -> Vec<GlossaryEntry> {
+        let descriptions = self
+            .skill
+            .iter()
+            .flat_map(|skill| skill.modes.iter().map(|mode| mode.format()))
+            .chain(self.rune.iter().map(|rune| rune.format()));
+        let mut keys = BTreeSet::new();
+        for tokens in descriptions {
+            for token in tokens.vec() {
+                if let Token::TermStart(_, Some(tips)) = token {
+                    keys.insert(tips.clone());
+                }
+            }
+        }
+
+        let mut entries = keys
+            .into_iter()
+            .filter(|key| self.in_glossary(key))
+            .map(|key| GlossaryEntry {
+                name: self.term[&format!("NM-{}", key)].tokens.clone(),
+                description: self.term[&format!("DC-{}", key)].tokens.clone(),
+                key,
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by_cached_key(|entry| (entry.name.to_string(), entry.key.clone()));
+        entries
+    }
+
+    /// Whether the tooltip `key` has an entry in the glossary: it has a name and a
+    /// description, and isn't the tooltip of a skill or a state.
+    pub fn in_glossary(&self, key: &str) -> bool {
+        let is_state = if self.global.state_ids.is_empty() {
+            // Databases generated before the state ids were recorded only list the
+            // keywords named as such by the game.
+            !key.starts_with("TIPS_") && !key.starts_with("MainParam:")
+        } else {
+            self.global.state_ids.iter().any(|id| id == key)
+        };
+        let is_skill = || {
+            self.skill
+                .iter()
+                .any(|skill| skill.id == key || skill.modes.iter().any(|mode| mode.id == key))
+        };
+        self.term.contains_key(&format!("NM-{}", key))
+            && self.term.contains_key(&format!("DC-{}", key))
+            && !is_state
+            && !is_skill()
+    }
+}
+
+/// A keyword of the game, like the cooldown or the elements, which the tooltips
+/// explain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlossaryEntry {
+    /// The name of the tooltip, like `TIPS_Cooldown` or `MainParam:1`.
+    pub key: String,
+    pub name: Tokens,
+    pub description: Tokens,
+}
+
+impl GlossaryEntry {
+    /// The id of the entry in the glossary page.
+    pub fn anchor(&self) -> String {
+        anchor(&self.key)
+    }
+
+    /// Whether every keyword of `query` starts a word of the name or the description,
+    /// or is close to one.
+    pub fn matches(&self, query: &str) -> bool {
+        let words = tokenize(&format!("{}\n{}", self.name, self.description));
+        tokenize(query).iter().all(|q| {
+            words
+                .iter()
+                .any(|word| word.starts_with(q.as_str()) || is_fuzzy_match(q, word))
+        })
+    }
+}
+
+/// The id of the glossary entry of the tooltip `key`. Keys may contain `:`, `*`, `+`, `/`
+/// and `-`, which are written as `-<hex code>-` so that `MainParam:1+` and `MainParam:1*`
+/// don't share an anchor.
+pub fn anchor(key: &str) -> String {
+    let mut anchor = String::new();
+    for c in key.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            anchor.push(c);
+        } else {
+            anchor.push_str(&format!("-{:x}-", c as u32));
+        }
+    }
+    anchor
+}
+
 impl Deref for TermRepository {
     type Target = HashMap<String, Term>;
 
@@ -69,3 +167,135 @@ impl DerefMut for TermRepository {
         &mut self.inner
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use super::*;
+    use skill::{Skill, SkillCategory, SkillRepository};
+    use {GlobalRepository, Rune, RuneRepository, Sprite};
+
+    fn term(text: &str) -> Term {
+        Term {
+            tokens: Tokens::from_vec(vec![Token::Text(text.to_string())]),
+        }
+    }
+
+    fn tips(key: &str) -> Vec<Token> {
+        vec![
+            Token::TermStart(format!("NM-{}", key), Some(key.to_string())),
+            Token::TermEnd,
+        ]
+    }
+
+    fn database() -> Database {
+        let terms = TermRepository::from_vec(vec![
+            ("NM-TIPS_Stun".to_string(), term("Stun")),
+            ("DC-TIPS_Stun".to_string(), term("Puts skills on cooldown.")),
+            ("NM-TIPS_Cooldown".to_string(), term("Cooldown")),
+            (
+                "DC-TIPS_Cooldown".to_string(),
+                term("Turns before a skill can be used again."),
+            ),
+            ("NM-TIPS_GearParam*".to_string(), term("Strengthen")),
+            ("NM-TIPS_Unused".to_string(), term("Unused")),
+            (
+                "DC-TIPS_Unused".to_string(),
+                term("Not linked by a description."),
+            ),
+            ("NM-MainParam:1".to_string(), term("Strength")),
+            (
+                "DC-MainParam:1".to_string(),
+                term("Raises physical damage."),
+            ),
+            ("NM-MainParam:1+".to_string(), term("Strength up")),
+            ("DC-MainParam:1+".to_string(), term("Raises strength.")),
+            ("NM-Burn".to_string(), term("Burn")),
+            ("DC-Burn".to_string(), term("A state.")),
+            ("NM-SKA01".to_string(), term("Slash")),
+            ("DC-SKA01".to_string(), term("A skill.")),
+        ]);
+        let description = [
+            "TIPS_Stun",
+            "TIPS_Cooldown",
+            "TIPS_GearParam*",
+            "MainParam:1",
+            "MainParam:1+",
+            "Burn",
+            "SKA01",
+        ]
+        .iter()
+        .flat_map(|key| tips(key))
+        .collect();
+        Database {
+            global: GlobalRepository {
+                state_ids: vec!["Burn".to_string()],
+                ..Default::default()
+            },
+            term: Rc::new(terms),
+            skill: Rc::new(SkillRepository::from_vec(vec![Skill {
+                hash: 1,
+                id: "SKA01".to_string(),
+                modes: vec![],
+                category: SkillCategory::Attack,
+                rarity: 1,
+                in_dictionary: true,
+                is_free: false,
+                name: "Slash".to_string(),
+            }])),
+            rune: Rc::new(RuneRepository::from_vec(vec![Rune {
+                hash: 1,
+                id: "RN01".to_string(),
+                order: 1,
+                icon: Sprite {
+                    index: 0,
+                    x: 0,
+                    y: 0,
+                    width: 0,
+                    height: 0,
+                },
+                rarity: 1,
+                name: "Ruby".to_string(),
+                description: Tokens::from_vec(description),
+            }])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_glossary() {
+        let database = database();
+        let glossary = database.glossary();
+        assert_eq!(
+            glossary.iter().map(|e| e.key.as_str()).collect::<Vec<_>>(),
+            vec!["TIPS_Cooldown", "MainParam:1", "MainParam:1+", "TIPS_Stun"]
+        );
+
+        assert!(glossary[0].matches("turns"));
+        assert!(glossary[0].matches("cooldwn"));
+        assert!(glossary[1].matches("physical"));
+        assert!(glossary[3].matches("skill cool"));
+        assert!(!glossary[3].matches("turns"));
+
+        assert!(database.in_glossary("TIPS_Stun"));
+        assert!(database.in_glossary("MainParam:1"));
+        assert!(!database.in_glossary("TIPS_GearParam*"));
+        assert!(!database.in_glossary("Burn"));
+        assert!(!database.in_glossary("SKA01"));
+
+        // Without state ids, only the keywords named as such by the game
+        let mut legacy = database.clone();
+        legacy.global.state_ids = vec![];
+        assert_eq!(legacy.glossary(), glossary);
+    }
+
+    #[test]
+    fn test_anchor() {
+        assert_eq!(anchor("TIPS_Cooldown"), "TIPS_Cooldown");
+        assert_eq!(anchor("TIPS_GearParam*"), "TIPS_GearParam-2a-");
+        assert_eq!(anchor("MainParam:1+"), "MainParam-3a-1-2b-");
+        assert_ne!(anchor("MainParam:1+"), anchor("MainParam:1*"));
+        assert_ne!(anchor("Accu-"), anchor("Accu-2d-"));
+    }
+}
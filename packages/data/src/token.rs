@@ -14,9 +14,50 @@ pub enum Token {
     Panic(String),
     TermStart(String, Option<String>),
     TermEnd,
+    /// A Unity rich-text tag like `<b>` or `<color=#FB0>`.
+    StyleStart(Style),
+    /// Closes the last style.
+    StyleEnd,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Style {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    /// A color like `#FB0` or `red`.
+    Color(String),
+    /// A size like `120%` or `24`.
+    Size(String),
 }
 
 impl Token {
+    /// Parses the inside of a rich-text tag, like `color=#FB0` or `/color`.
+    /// Returns `None` for anything else, like `sprite name=gs` or a variable.
+    pub fn from_rich_text_tag(tag: &str) -> Option<Token> {
+        if let Some(name) = tag.strip_prefix('/') {
+            return match name {
+                "b" | "i" | "u" | "s" | "color" | "size" => Some(Token::StyleEnd),
+                _ => None,
+            };
+        }
+        let (name, value) = match tag.find('=') {
+            Some(i) => (&tag[..i], Some(tag[i + 1..].trim_matches('"'))),
+            None => (tag, None),
+        };
+        let style = match (name, value) {
+            ("b", None) => Style::Bold,
+            ("i", None) => Style::Italic,
+            ("u", None) => Style::Underline,
+            ("s", None) => Style::Strikethrough,
+            ("color", Some(color)) => Style::Color(color.to_string()),
+            ("size", Some(size)) => Style::Size(size.to_string()),
+            _ => return None,
+        };
+        Some(Token::StyleStart(style))
+    }
+
     pub fn write(&self, other: &mut Tokens) {
         other.push(self.clone());
     }
@@ -149,6 +190,8 @@ impl Display for Tokens {
                 Token::Panic(s) => write!(f, "!{}!", s)?,
                 Token::TermStart(_, _) => {}
                 Token::TermEnd => {}
+                Token::StyleStart(_) => {}
+                Token::StyleEnd => {}
             }
         }
         Ok(())
@@ -0,0 +1,211 @@
+use std::ops::Range;
+
+use kana::to_hiragana;
+
+/// Folds a character for search: lowercase, halfwidth for fullwidth ASCII,
+/// and Hiragana for Katakana.
+pub fn normalize(c: char) -> char {
+    let c = match c {
+        '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+        _ => c,
+    };
+    to_hiragana(c.to_lowercase().next().unwrap_or(c))
+}
+
+/// Splits `text` into normalized search keywords.
+///
+/// Words are separated by any character which is not alphanumeric.
+/// Han, Hiragana and Katakana have no word separators, so runs of them are split
+/// into overlapping bigrams instead. A run of a single character is kept as is.
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = vec![];
+    let mut word = String::new();
+    let mut run = vec![];
+
+    for c in text.chars().map(normalize) {
+        if is_cjk(c) {
+            push_word(&mut tokens, &mut word);
+            run.push(c);
+        } else {
+            push_run(&mut tokens, &mut run);
+            if c.is_alphanumeric() {
+                word.push(c);
+            } else {
+                push_word(&mut tokens, &mut word);
+            }
+        }
+    }
+    push_word(&mut tokens, &mut word);
+    push_run(&mut tokens, &mut run);
+    tokens
+}
+
+fn push_word(tokens: &mut Vec<String>, word: &mut String) {
+    if !word.is_empty() {
+        tokens.push(std::mem::take(word));
+    }
+}
+
+fn push_run(tokens: &mut Vec<String>, run: &mut Vec<char>) {
+    if run.len() == 1 {
+        tokens.push(run[0].to_string());
+    } else {
+        for pair in run.windows(2) {
+            tokens.push(pair.iter().collect());
+        }
+    }
+    run.clear();
+}
+
+/// Whether `query` is within a small edit distance of `keyword`, or of a prefix of it
+/// while the word is still being typed. Transposed characters count as one edit.
+///
+/// Words shorter than four characters and CJK words never match fuzzily.
+pub fn is_fuzzy_match(query: &str, keyword: &str) -> bool {
+    let query = query.chars().collect::<Vec<_>>();
+    let keyword = keyword.chars().collect::<Vec<_>>();
+    let max = match query.len() {
+        0..=3 => return false,
+        4..=7 => 1,
+        _ => 2,
+    };
+    if query.iter().chain(keyword.iter()).any(|c| is_cjk(*c)) || keyword.len() + max < query.len() {
+        return false;
+    }
+
+    // d[i][j] is the distance between query[..i] and keyword[..j]
+    let mut d = vec![vec![0; keyword.len() + 1]; query.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    d[0] = (0..=keyword.len()).collect();
+    for i in 1..=query.len() {
+        for j in 1..=keyword.len() {
+            let cost = usize::from(query[i - 1] != keyword[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && query[i - 1] == keyword[j - 2] && query[i - 2] == keyword[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[query.len()].iter().any(|distance| *distance <= max)
+}
+
+/// Finds the byte ranges of `text` to highlight for the search `terms`, sorted and merged.
+///
+/// Terms match anywhere after [`normalize`]. A word which only matches a term fuzzily
+/// and starts with the same character is highlighted as a whole.
+pub fn highlight(text: &str, terms: &[String]) -> Vec<Range<usize>> {
+    let chars = text
+        .char_indices()
+        .map(|(i, c)| (i..i + c.len_utf8(), normalize(c)))
+        .collect::<Vec<_>>();
+    let terms = terms
+        .iter()
+        .map(|term| term.chars().map(normalize).collect::<String>())
+        .collect::<Vec<_>>();
+    let mut ranges = vec![];
+
+    for term in &terms {
+        let term = term.chars().collect::<Vec<_>>();
+        if term.is_empty() || term.len() > chars.len() {
+            continue;
+        }
+        for start in 0..=chars.len() - term.len() {
+            let window = &chars[start..start + term.len()];
+            if window.iter().zip(term.iter()).all(|((_, c), t)| c == t) {
+                ranges.push(window[0].0.start..window[term.len() - 1].0.end);
+            }
+        }
+    }
+
+    let is_word = |c: char| c.is_alphanumeric() && !is_cjk(c);
+    let mut word_start = None;
+    for (i, (range, c)) in chars.iter().enumerate() {
+        if !is_word(*c) {
+            continue;
+        }
+        let start = *word_start.get_or_insert(i);
+        if chars.get(i + 1).is_some_and(|(_, c)| is_word(*c)) {
+            continue;
+        }
+        word_start = None;
+
+        let bytes = chars[start].0.start..range.end;
+        let word = chars[start..=i].iter().map(|(_, c)| c).collect::<String>();
+        let overlaps = ranges
+            .iter()
+            .any(|r| r.start < bytes.end && bytes.start < r.end);
+        // Same first character rule as `SearchIndex::search_keyword`, so only words
+        // which could have matched are marked
+        let is_match = |term: &String| {
+            term.chars().next() == word.chars().next() && is_fuzzy_match(term, &word)
+        };
+        if !overlaps && terms.iter().any(is_match) {
+            ranges.push(bytes);
+        }
+    }
+
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<Range<usize>> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+fn is_cjk(c: char) -> bool {
+    match c {
+        '\u{3040}'..='\u{30ff}' // Hiragana, Katakana
+        | '\u{31f0}'..='\u{31ff}' // Katakana phonetic extensions
+        | '\u{3400}'..='\u{4dbf}' // CJK extension A
+        | '\u{4e00}'..='\u{9fff}' // CJK unified ideographs
+        | '\u{f900}'..='\u{faff}' // CJK compatibility ideographs
+        | '\u{ff66}'..='\u{ff9f}' // Halfwidth Katakana
+        => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(tokenize(""), Vec::<String>::new());
+        assert_eq!(tokenize("Fire Ball, +10%"), vec!["fire", "ball", "10"]);
+        assert_eq!(tokenize("炎"), vec!["炎"]);
+        assert_eq!(tokenize("ファイア"), vec!["ふぁ", "ぁい", "いあ"]);
+        assert_eq!(tokenize("ＨＰを１０回復"), vec!["hp", "を", "10", "回復"]);
+        assert_eq!(tokenize("화염 공격"), vec!["화염", "공격"]);
+    }
+
+    #[test]
+    fn test_is_fuzzy_match() {
+        assert!(is_fuzzy_match("fier", "fire"));
+        assert!(is_fuzzy_match("fireboll", "fireball"));
+        assert!(is_fuzzy_match("thundr", "thunderbolt"));
+        assert!(!is_fuzzy_match("fie", "fire"));
+        assert!(!is_fuzzy_match("water", "fire"));
+    }
+
+    #[test]
+    fn test_highlight() {
+        let terms = |terms: &[&str]| terms.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+        assert_eq!(highlight("Fire Ball", &terms(&["fire"])), vec![0..4]);
+        assert_eq!(highlight("Fire Ball", &terms(&["fi", "ire"])), vec![0..4]);
+        assert_eq!(highlight("Fire Ball", &terms(&["baal"])), vec![5..9]);
+        assert_eq!(
+            highlight("ファイアボール", &terms(&["ぼーる"])),
+            vec![12..21]
+        );
+        assert_eq!(highlight("Fire Ball", &terms(&["ice"])), vec![]);
+        assert_eq!(highlight("Fire Ball", &terms(&["wall"])), vec![]);
+    }
+}
@@ -0,0 +1,324 @@
+//! Packs the icons used by the database into compact atlases, instead of shipping the
+//! whole textures of the game.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryInto;
+use std::path::Path;
+
+use image::imageops::FilterType;
+use image::{ImageFormat, RgbaImage};
+use json::JsonValue;
+
+use data::Sprite;
+use idhash::IdHash;
+use sprite::SpriteSource;
+
+/// The maximum width and height of an atlas.
+pub const MAX_SIZE: u32 = 2048;
+/// The transparent gap between icons, so scaled icons don't bleed into each other.
+const PADDING: u32 = 2;
+/// The resolutions of the pages, upscaled with nearest neighbor to keep the pixel art
+/// crisp. The web app picks one by the size of the icon and the device pixel ratio.
+pub const SCALES: [u32; 3] = [1, 2, 4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtlasFormat {
+    WebP,
+    Png,
+}
+
+impl AtlasFormat {
+    pub fn parse(s: &str) -> Result<AtlasFormat, String> {
+        match s {
+            "webp" => Ok(AtlasFormat::WebP),
+            "png" => Ok(AtlasFormat::Png),
+            _ => Err(format!("unknown atlas format: {:?}", s)),
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            AtlasFormat::WebP => "webp",
+            AtlasFormat::Png => "png",
+        }
+    }
+
+    fn image_format(self) -> ImageFormat {
+        match self {
+            AtlasFormat::WebP => ImageFormat::WebP,
+            AtlasFormat::Png => ImageFormat::Png,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page {
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Atlas {
+    pub pages: Vec<Page>,
+    /// The source of each icon by name, and its place in the pages. `Sprite::index` is
+    /// the page from 1, as in the `sprite-<index>` class.
+    pub sprites: BTreeMap<String, (SpriteSource, Sprite)>,
+}
+
+/// Places the icons on shelves, the tallest first, starting a new page when one is full.
+/// Icons cropped from the same place are packed once.
+pub fn pack(sources: BTreeMap<String, SpriteSource>, max_size: u32) -> Atlas {
+    let mut unique = sources.values().cloned().collect::<Vec<_>>();
+    unique.sort_by_key(|s| {
+        (
+            Reverse(s.height),
+            Reverse(s.width),
+            s.texture.clone(),
+            s.x,
+            s.y,
+        )
+    });
+    unique.dedup();
+
+    let mut pages = vec![Page {
+        width: 0,
+        height: 0,
+    }];
+    let mut places = HashMap::new();
+    let (mut x, mut y, mut shelf) = (0, 0, 0);
+    for source in unique {
+        assert!(
+            source.width <= max_size && source.height <= max_size,
+            "sprite larger than an atlas: {:?}",
+            source
+        );
+        if x + source.width > max_size {
+            x = 0;
+            y += shelf + PADDING;
+            shelf = 0;
+        }
+        if y + source.height > max_size {
+            pages.push(Page {
+                width: 0,
+                height: 0,
+            });
+            x = 0;
+            y = 0;
+            shelf = 0;
+        }
+        let page = pages.last_mut().unwrap();
+        page.width = page.width.max(x + source.width);
+        page.height = page.height.max(y + source.height);
+        let sprite = Sprite {
+            index: pages.len().try_into().unwrap(),
+            x: x.try_into().unwrap(),
+            y: y.try_into().unwrap(),
+            width: source.width.try_into().unwrap(),
+            height: source.height.try_into().unwrap(),
+        };
+        x += source.width + PADDING;
+        shelf = shelf.max(source.height);
+        places.insert(source, sprite);
+    }
+
+    let sprites = sources
+        .into_iter()
+        .map(|(name, source)| {
+            let sprite = places[&source].clone();
+            (name, (source, sprite))
+        })
+        .collect();
+    Atlas { pages, sprites }
+}
+
+impl Atlas {
+    /// The place of the icon `name`, which must have been packed.
+    pub fn sprite(&self, name: &str) -> Sprite {
+        match self.sprites.get(name) {
+            Some((_, sprite)) => sprite.clone(),
+            None => panic!("sprite not packed: {}", name),
+        }
+    }
+
+    /// Crops the icons from the textures into the pages.
+    pub fn render(&self) -> Vec<RgbaImage> {
+        let mut images = self
+            .pages
+            .iter()
+            .map(|p| RgbaImage::new(p.width, p.height))
+            .collect::<Vec<_>>();
+        let mut textures = HashMap::new();
+        for (source, sprite) in self.sprites.values() {
+            let texture = textures.entry(source.texture.clone()).or_insert_with(|| {
+                image::open(&source.texture)
+                    .unwrap_or_else(|e| panic!("{}: {}", source.texture.display(), e))
+                    .into_rgba8()
+            });
+            let icon =
+                image::imageops::crop_imm(texture, source.x, source.y, source.width, source.height);
+            image::imageops::replace(
+                &mut images[sprite.index as usize - 1],
+                &*icon,
+                sprite.x.into(),
+                sprite.y.into(),
+            );
+        }
+        images
+    }
+
+    /// Writes the pages as `images/atlas-<index>@<scale>x-<hash>.<ext>` into `output` in
+    /// each of the `SCALES`, with the `sprites.css` which shows them and the
+    /// `images/atlas.json` manifest. The hash changes with the content, so the pages can
+    /// be cached forever.
+    pub fn write(&self, output: &Path, format: AtlasFormat) {
+        let dir = output.join("images");
+        std::fs::create_dir_all(&dir).unwrap();
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy();
+            if name.starts_with("atlas-") {
+                std::fs::remove_file(&path).unwrap();
+            }
+        }
+
+        let hasher = IdHash::new(0, 32);
+        let mut files = vec![];
+        for (i, image) in self.render().iter().enumerate() {
+            let mut page_files = vec![];
+            for scale in SCALES {
+                let image = if scale == 1 {
+                    image.clone()
+                } else {
+                    let (width, height) = image.dimensions();
+                    image::imageops::resize(
+                        image,
+                        width * scale,
+                        height * scale,
+                        FilterType::Nearest,
+                    )
+                };
+                let mut bytes = std::io::Cursor::new(vec![]);
+                image.write_to(&mut bytes, format.image_format()).unwrap();
+                let bytes = bytes.into_inner();
+                let file = format!(
+                    "atlas-{}@{}x-{:08x}.{}",
+                    i + 1,
+                    scale,
+                    hasher.id_hash(&bytes),
+                    format.extension()
+                );
+                std::fs::write(dir.join(&file), bytes).unwrap();
+                page_files.push(file);
+            }
+            files.push(page_files);
+        }
+
+        std::fs::write(output.join("sprites.css"), self.css(&files)).unwrap();
+        std::fs::write(dir.join("atlas.json"), self.manifest(&files).pretty(2)).unwrap();
+    }
+
+    /// The background size is the size of the page at 1x whatever the resolution, so
+    /// the icons are placed the same.
+    fn css(&self, files: &[Vec<String>]) -> String {
+        let mut css = String::new();
+        for (i, (page, files)) in self.pages.iter().zip(files).enumerate() {
+            css.push_str(&format!(
+                ".sprite-{} {{\n    background-image: url(\"/images/{}\");\n    background-size: calc({}px * var(--s)) calc({}px * var(--s));\n}}\n",
+                i + 1,
+                files[0],
+                page.width,
+                page.height
+            ));
+            for (scale, file) in SCALES.iter().zip(files).skip(1) {
+                css.push_str(&format!(
+                    ".sprite-{}.sprite-{}x {{\n    background-image: url(\"/images/{}\");\n}}\n",
+                    i + 1,
+                    scale,
+                    file
+                ));
+            }
+        }
+        css
+    }
+
+    fn manifest(&self, files: &[Vec<String>]) -> JsonValue {
+        let mut pages = JsonValue::new_array();
+        for (page, files) in self.pages.iter().zip(files) {
+            let mut images = JsonValue::new_object();
+            for (scale, file) in SCALES.iter().zip(files) {
+                images[format!("{}x", scale).as_str()] = file.clone().into();
+            }
+            pages
+                .push(object! {
+                    "files" => images,
+                    "width" => page.width,
+                    "height" => page.height,
+                })
+                .unwrap();
+        }
+        let mut sprites = JsonValue::new_object();
+        for (name, (_, sprite)) in &self.sprites {
+            sprites[name.as_str()] = object! {
+                "page" => sprite.index,
+                "x" => sprite.x,
+                "y" => sprite.y,
+                "width" => sprite.width,
+                "height" => sprite.height,
+            };
+        }
+        object! { "pages" => pages, "sprites" => sprites }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn source(x: u32, width: u32, height: u32) -> SpriteSource {
+        SpriteSource {
+            texture: PathBuf::from("texture.png"),
+            x,
+            y: 0,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_pack() {
+        let mut sources = BTreeMap::new();
+        sources.insert("a".to_string(), source(0, 40, 40));
+        sources.insert("b".to_string(), source(100, 60, 30));
+        sources.insert("c".to_string(), source(200, 50, 50));
+        sources.insert("d".to_string(), source(300, 40, 40));
+        // The same icon as `a`
+        sources.insert("e".to_string(), source(0, 40, 40));
+        let atlas = pack(sources, 100);
+
+        let place = |name: &str| {
+            let s = atlas.sprite(name);
+            (s.index, s.x, s.y)
+        };
+        assert_eq!(place("c"), (1, 0, 0));
+        assert_eq!(place("a"), (1, 52, 0));
+        assert_eq!(place("d"), (1, 0, 52));
+        assert_eq!(place("e"), place("a"));
+        // Doesn't fit below the second shelf
+        assert_eq!(place("b"), (2, 0, 0));
+        assert_eq!(
+            atlas.pages,
+            vec![
+                Page {
+                    width: 92,
+                    height: 92
+                },
+                Page {
+                    width: 60,
+                    height: 30
+                }
+            ]
+        );
+    }
+}
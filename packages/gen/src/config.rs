@@ -0,0 +1,177 @@
+use std::path::{Path, PathBuf};
+
+use yaml_rust::{Yaml, YamlLoader};
+
+use atlas::AtlasFormat;
+use data::LANGUAGES;
+
+/// The Unity assets inside the dump, as exported by Asset Ripper.
+const ASSET: &str = "asset/ExportedProject/Assets";
+
+/// Where the pipeline reads the dump of the game and writes the databases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// The dump of the game, with `db.json`. The CSV files for debugging are written
+    /// here too.
+    pub dump: PathBuf,
+    /// The Unity assets, with the terms and the sprites. Defaults to
+    /// `<dump>/asset/ExportedProject/Assets`.
+    pub asset: Option<PathBuf>,
+    /// The files of the web app, where `i18n/<lang>` is written.
+    pub output: PathBuf,
+    pub languages: Vec<&'static str>,
+    /// The image format of the sprite atlases.
+    pub atlas_format: AtlasFormat,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            dump: PathBuf::from("dump"),
+            asset: None,
+            output: PathBuf::from("public"),
+            languages: LANGUAGES.to_vec(),
+            atlas_format: AtlasFormat::WebP,
+        }
+    }
+}
+
+impl Config {
+    /// Reads a YAML config file. Relative paths are relative to the file, and missing
+    /// keys keep their default.
+    ///
+    /// ```yaml
+    /// dump: ../dump
+    /// asset: ../dump/asset/ExportedProject/Assets
+    /// output: ../public
+    /// languages: [ja, en]
+    /// atlas_format: png
+    /// ```
+    pub fn load(path: &Path) -> Result<Config, String> {
+        let s = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let base = path.parent().unwrap_or(Path::new(""));
+        Config::from_yaml(&s, base).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn from_yaml(s: &str, base: &Path) -> Result<Config, String> {
+        let docs = YamlLoader::load_from_str(s).map_err(|e| e.to_string())?;
+        let mut config = Config::default();
+        let doc = match docs.into_iter().next() {
+            Some(doc) => doc,
+            None => return Ok(config),
+        };
+        if doc.as_hash().is_none() {
+            return Err("expected a mapping".to_string());
+        }
+
+        let path = |key: &str| match &doc[key] {
+            Yaml::BadValue => Ok(None),
+            Yaml::String(s) => Ok(Some(base.join(s))),
+            _ => Err(format!("{} should be a path", key)),
+        };
+        if let Some(dump) = path("dump")? {
+            config.dump = dump;
+        }
+        if let Some(asset) = path("asset")? {
+            config.asset = Some(asset);
+        }
+        if let Some(output) = path("output")? {
+            config.output = output;
+        }
+        match &doc["languages"] {
+            Yaml::BadValue => (),
+            Yaml::String(s) => config.languages = parse_languages(s)?,
+            Yaml::Array(langs) => {
+                let langs = langs
+                    .iter()
+                    .map(|l| l.as_str().ok_or("languages should be strings".to_string()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                config.languages = parse_languages(&langs.join(","))?;
+            }
+            _ => return Err("languages should be a list".to_string()),
+        }
+        match &doc["atlas_format"] {
+            Yaml::BadValue => (),
+            Yaml::String(s) => config.atlas_format = AtlasFormat::parse(s)?,
+            _ => return Err("atlas_format should be webp or png".to_string()),
+        }
+        Ok(config)
+    }
+
+    pub fn asset(&self) -> PathBuf {
+        self.asset.clone().unwrap_or_else(|| self.dump.join(ASSET))
+    }
+
+    pub fn db_json(&self) -> PathBuf {
+        self.dump.join("db.json")
+    }
+
+    pub fn table_csv(&self, name: &str) -> PathBuf {
+        self.dump.join(format!("table/{}.csv", name))
+    }
+
+    pub fn term_csv(&self, lang: &str) -> PathBuf {
+        self.dump.join(format!("{}.csv", lang))
+    }
+
+    pub fn i2_languages(&self) -> PathBuf {
+        self.asset().join("Resources/I2Languages.asset")
+    }
+
+    /// The directory of the database of `lang`, with its `manifest.msgpack`.
+    pub fn i18n(&self, lang: &str) -> PathBuf {
+        self.output.join(format!("i18n/{}", lang))
+    }
+}
+
+/// Parses a comma separated list of languages, or `all`.
+pub fn parse_languages(s: &str) -> Result<Vec<&'static str>, String> {
+    if s == "all" {
+        return Ok(LANGUAGES.to_vec());
+    }
+    s.split(',')
+        .map(|lang| {
+            let lang = lang.trim();
+            LANGUAGES
+                .iter()
+                .find(|l| **l == lang)
+                .cloned()
+                .ok_or(format!("unknown language: {:?}", lang))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_yaml() {
+        let base = Path::new("config");
+        assert_eq!(Config::from_yaml("", base), Ok(Config::default()));
+
+        let config = Config::from_yaml("dump: ../dump\nlanguages: [ja, en]", base).unwrap();
+        assert_eq!(config.dump, Path::new("config/../dump"));
+        assert_eq!(
+            config.asset(),
+            Path::new("config/../dump/asset/ExportedProject/Assets")
+        );
+        assert_eq!(config.output, Path::new("public"));
+        assert_eq!(config.languages, vec!["ja", "en"]);
+
+        assert_eq!(config.atlas_format, AtlasFormat::WebP);
+
+        let config =
+            Config::from_yaml("asset: /assets\nlanguages: all\natlas_format: png", base).unwrap();
+        assert_eq!(
+            config.i2_languages(),
+            Path::new("/assets/Resources/I2Languages.asset")
+        );
+        assert_eq!(config.languages.len(), LANGUAGES.len());
+        assert_eq!(config.atlas_format, AtlasFormat::Png);
+
+        assert!(Config::from_yaml("languages: [ja, xx]", base).is_err());
+        assert!(Config::from_yaml("dump: [1]", base).is_err());
+        assert!(Config::from_yaml("atlas_format: gif", base).is_err());
+    }
+}
@@ -1,7 +1,13 @@
+use std::collections::BTreeSet;
+
 use data::term::TermRepository;
 use data::GlobalRepository;
 
-pub fn process_global(terms: &TermRepository) -> GlobalRepository {
+pub fn process_global(
+    terms: &TermRepository,
+    state_ids: &BTreeSet<String>,
+    enemy_ids: &BTreeSet<String>,
+) -> GlobalRepository {
     let mut rarity_colors = terms
         .iter()
         .filter(|(k, _)| k.starts_with("CLR-Star-Rarity-"))
@@ -16,5 +22,9 @@ pub fn process_global(terms: &TermRepository) -> GlobalRepository {
         .map(|(_, v)| v.clone())
         .collect::<Vec<_>>();
 
-    GlobalRepository { rarity_colors }
+    GlobalRepository {
+        rarity_colors,
+        state_ids: state_ids.iter().cloned().collect(),
+        enemy_ids: enemy_ids.iter().cloned().collect(),
+    }
 }
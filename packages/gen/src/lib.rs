@@ -0,0 +1,32 @@
+//! Generates the databases of the web app from a dump of the game.
+//!
+//! ```no_run
+//! let pipeline = gen::Pipeline::new(gen::Config::default());
+//! pipeline.generate("", |lang, database| pipeline.write(lang, &database));
+//! pipeline.write_atlas();
+//! ```
+
+extern crate ahash;
+extern crate data;
+extern crate image;
+#[macro_use]
+extern crate json;
+extern crate regex;
+extern crate yaml_rust;
+
+pub use atlas::AtlasFormat;
+pub use config::{parse_languages, Config};
+pub use pipeline::{manifest_version, read_database, write_database, write_table_csvs, Pipeline};
+
+mod atlas;
+mod config;
+mod global;
+mod idhash;
+pub mod lint;
+mod pipeline;
+mod rune;
+mod skill;
+mod sprite;
+mod state;
+mod table;
+mod terms;
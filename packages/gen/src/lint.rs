@@ -0,0 +1,286 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use json::JsonValue;
+
+use data::term::TermRepository;
+use data::token::{Token, Tokens};
+use data::Database;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Issue {
+    /// A variable left in the text, which the formatter didn't know.
+    UnresolvedVar(String),
+    Error(String),
+    /// A formatting rule that failed, which used to abort the run.
+    Panic(String),
+    MissingTerm(String),
+    UnmatchedTermStart(String),
+    UnmatchedTermEnd,
+}
+
+impl Issue {
+    pub const KINDS: [&'static str; 5] = ["var", "error", "panic", "missing-term", "unmatched"];
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Issue::UnresolvedVar(_) => "var",
+            Issue::Error(_) => "error",
+            Issue::Panic(_) => "panic",
+            Issue::MissingTerm(_) => "missing-term",
+            Issue::UnmatchedTermStart(_) | Issue::UnmatchedTermEnd => "unmatched",
+        }
+    }
+
+    fn detail(&self) -> &str {
+        match self {
+            Issue::UnresolvedVar(s)
+            | Issue::Error(s)
+            | Issue::Panic(s)
+            | Issue::MissingTerm(s)
+            | Issue::UnmatchedTermStart(s) => s,
+            Issue::UnmatchedTermEnd => "",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    /// Where the description comes from, like `skill FireBall/1`.
+    pub location: String,
+    pub issue: Issue,
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} {}",
+            self.location,
+            self.issue.kind(),
+            self.issue.detail()
+        )
+    }
+}
+
+/// Checks one description, and that the tooltips it links have a name and a text in
+/// `terms`.
+pub fn lint_tokens(tokens: &Tokens, terms: &TermRepository) -> Vec<Issue> {
+    let mut issues = vec![];
+    let mut open = vec![];
+    for token in tokens.vec() {
+        match token {
+            Token::Var(name) => issues.push(Issue::UnresolvedVar(name.clone())),
+            // `TermRepository::get` puts the key as an error inside the term
+            Token::Error(text) if open.last() == Some(&text) => {
+                issues.push(Issue::MissingTerm(text.clone()))
+            }
+            Token::Error(text) => issues.push(Issue::Error(text.clone())),
+            Token::Panic(text) => issues.push(Issue::Panic(text.clone())),
+            Token::TermStart(name, tips) => {
+                open.push(name);
+                if let Some(tips) = tips {
+                    for key in [format!("NM-{}", tips), format!("DC-{}", tips)] {
+                        if !terms.contains_key(&key) {
+                            issues.push(Issue::MissingTerm(key));
+                        }
+                    }
+                }
+            }
+            Token::TermEnd if open.is_empty() => issues.push(Issue::UnmatchedTermEnd),
+            Token::TermEnd => {
+                open.pop();
+            }
+            _ => (),
+        }
+    }
+    for name in open {
+        issues.push(Issue::UnmatchedTermStart(name.clone()));
+    }
+    issues
+}
+
+/// Checks the descriptions of every skill mode and rune, and every term.
+pub fn lint_database(database: &Database) -> Vec<Finding> {
+    let mut findings = vec![];
+    let mut push = |location: String, tokens: Tokens| {
+        for issue in lint_tokens(&tokens, &database.term) {
+            findings.push(Finding {
+                location: location.clone(),
+                issue,
+            });
+        }
+    };
+    for skill in database.skill.iter() {
+        for mode in &skill.modes {
+            push(format!("skill {}/{}", skill.id, mode.id), mode.format());
+        }
+    }
+    for rune in database.rune.iter() {
+        push(format!("rune {}", rune.id), rune.format());
+    }
+    let mut keys = database.term.keys().collect::<Vec<_>>();
+    keys.sort();
+    for key in keys {
+        let id = key.split_once('-').map_or(key.as_str(), |(_, id)| id);
+        let kind = if database.global.state_ids.iter().any(|s| s == id) {
+            "state"
+        } else {
+            "term"
+        };
+        push(
+            format!("{} {}", kind, key),
+            database.term[key].tokens.clone(),
+        );
+    }
+    findings
+}
+
+pub struct Report {
+    pub lang: String,
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    pub fn counts(&self) -> BTreeMap<&'static str, usize> {
+        let mut counts = Issue::KINDS
+            .iter()
+            .map(|k| (*k, 0))
+            .collect::<BTreeMap<_, _>>();
+        for finding in &self.findings {
+            *counts.get_mut(finding.issue.kind()).unwrap() += 1;
+        }
+        counts
+    }
+}
+
+/// Prints the number of issues of each kind per language.
+pub fn print_summary(reports: &[Report]) {
+    print!("{:<8}", "lang");
+    for kind in Issue::KINDS.iter() {
+        print!("{:>14}", kind);
+    }
+    println!();
+    for report in reports {
+        let counts = report.counts();
+        print!("{:<8}", report.lang);
+        for kind in Issue::KINDS.iter() {
+            print!("{:>14}", counts[kind]);
+        }
+        println!();
+    }
+}
+
+pub fn to_json(reports: &[Report]) -> JsonValue {
+    let mut languages = JsonValue::new_array();
+    for report in reports {
+        let mut counts = JsonValue::new_object();
+        for (kind, count) in report.counts() {
+            counts[kind] = count.into();
+        }
+        let mut findings = JsonValue::new_array();
+        for finding in &report.findings {
+            findings
+                .push(object! {
+                    "location" => finding.location.clone(),
+                    "kind" => finding.issue.kind(),
+                    "detail" => finding.issue.detail(),
+                })
+                .unwrap();
+        }
+        languages
+            .push(object! {
+                "lang" => report.lang.clone(),
+                "counts" => counts,
+                "findings" => findings,
+            })
+            .unwrap();
+    }
+    object! { "languages" => languages }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use data::term::Term;
+
+    use super::*;
+
+    fn terms(keys: &[&str]) -> TermRepository {
+        TermRepository::from_vec(
+            keys.iter()
+                .map(|k| {
+                    let tokens = Tokens::from_vec(vec![Token::Text(k.to_string())]);
+                    (k.to_string(), Term { tokens })
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_lint_tokens() {
+        let tokens = Tokens::from_vec(vec![
+            Token::TermStart("NM-Fire".to_string(), None),
+            Token::Error("NM-Fire".to_string()),
+            Token::TermEnd,
+            Token::Var("lasthit".to_string()),
+            Token::Error("oops".to_string()),
+            Token::TermEnd,
+            Token::TermStart("NM-Ice".to_string(), None),
+        ]);
+        assert_eq!(
+            lint_tokens(&tokens, &terms(&[])),
+            vec![
+                Issue::MissingTerm("NM-Fire".to_string()),
+                Issue::UnresolvedVar("lasthit".to_string()),
+                Issue::Error("oops".to_string()),
+                Issue::UnmatchedTermEnd,
+                Issue::UnmatchedTermStart("NM-Ice".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_tips() {
+        let tokens = Tokens::from_vec(vec![
+            Token::TermStart("WD-Cooldown".to_string(), Some("TIPS_Cooldown".to_string())),
+            Token::Text("Cooldown".to_string()),
+            Token::TermEnd,
+            Token::TermStart(
+                "NM-TIPS_UseInit".to_string(),
+                Some("TIPS_UseInit".to_string()),
+            ),
+            Token::Text("Use at start".to_string()),
+            Token::TermEnd,
+        ]);
+        let terms = terms(&["NM-TIPS_Cooldown", "DC-TIPS_Cooldown", "NM-TIPS_UseInit"]);
+        assert_eq!(
+            lint_tokens(&tokens, &terms),
+            vec![Issue::MissingTerm("DC-TIPS_UseInit".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_lint_database() {
+        let mut term = terms(&["NM-Burn", "NM-Stun"]);
+        term.insert(
+            "DC-Burn".to_string(),
+            Term {
+                tokens: Tokens::from_vec(vec![Token::Var("turns".to_string())]),
+            },
+        );
+        let mut database = Database {
+            term: Rc::new(term),
+            ..Default::default()
+        };
+        database.global.state_ids = vec!["Burn".to_string()];
+        assert_eq!(
+            lint_database(&database),
+            vec![Finding {
+                location: "state DC-Burn".to_string(),
+                issue: Issue::UnresolvedVar("turns".to_string()),
+            }]
+        );
+    }
+}
@@ -1,39 +1,37 @@
-extern crate ahash;
 extern crate clap;
 extern crate data;
-extern crate json;
-extern crate regex;
+extern crate gen;
 extern crate serde;
-extern crate yaml_rust;
 
 use clap::{Parser, Subcommand};
-use json::JsonValue;
-use std::rc::Rc;
+use std::path::{Path, PathBuf};
 
-use data::LANGUAGES;
-use state::state_repository_from_dump;
-use table::act::ActTable;
-use table::act_node::ActNodeTable;
-use table::enemy::EnemyTable;
-use table::rune::RuneTable;
-use table::skill::SkillTable;
-use table::skill_mode::SkillModeTable;
-use table::sm_act::SmActTable;
-use table::state::StateTable;
-use table::{Table, UnknownTable};
+use gen::{lint, parse_languages, AtlasFormat, Config, Pipeline};
 
-mod global;
-mod idhash;
-mod rune;
-mod skill;
-mod sprite;
-mod state;
-mod table;
-mod terms;
+/// The config file read when `--config` isn't given, if it exists.
+const DEFAULT_CONFIG: &str = "gen.yaml";
 
 #[derive(Debug, Parser)]
 #[command(name = "gen")]
 struct Cli {
+    /// Config file with the paths and languages, `gen.yaml` when present
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    /// The dump of the game, with `db.json`
+    #[arg(long, global = true)]
+    dump: Option<PathBuf>,
+    /// The Unity assets, by default `<dump>/asset/ExportedProject/Assets`
+    #[arg(long, global = true)]
+    asset: Option<PathBuf>,
+    /// The files of the web app, where `i18n/<lang>` is written
+    #[arg(long, global = true)]
+    output: Option<PathBuf>,
+    /// Comma separated languages, or `all`
+    #[arg(long, global = true)]
+    lang: Option<String>,
+    /// Image format of the sprite atlases, `webp` or `png`
+    #[arg(long, global = true)]
+    atlas_format: Option<String>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -42,107 +40,123 @@ struct Cli {
 enum Commands {
     Table,
     Database {
-        #[arg(long, default_value = "all")]
-        lang: String,
         #[arg(long, default_value_t = false)]
         write: bool,
+        /// Game version of the dump, recorded in the database
+        #[arg(long)]
+        version: String,
     },
+    /// Report broken descriptions and terms: unresolved variables, errors, missing terms
+    /// and tooltips, and unmatched terms
+    Lint {
+        /// Check the databases in the output instead of generating them from the dump
+        #[arg(long, default_value_t = false)]
+        built: bool,
+        /// Write the findings as JSON to this file, or `-` for stdout
+        #[arg(long)]
+        json: Option<String>,
+        /// Exit with an error if anything is found
+        #[arg(long, default_value_t = false)]
+        deny: bool,
+    },
+}
+
+impl Cli {
+    /// The config file, overridden by the options.
+    fn config(&self) -> Result<Config, String> {
+        let mut config = match &self.config {
+            Some(path) => Config::load(path)?,
+            None if Path::new(DEFAULT_CONFIG).exists() => Config::load(Path::new(DEFAULT_CONFIG))?,
+            None => Config::default(),
+        };
+        if let Some(dump) = &self.dump {
+            config.dump = dump.clone();
+        }
+        if let Some(asset) = &self.asset {
+            config.asset = Some(asset.clone());
+        }
+        if let Some(output) = &self.output {
+            config.output = output.clone();
+        }
+        if let Some(lang) = &self.lang {
+            config.languages = parse_languages(lang)?;
+        }
+        if let Some(format) = &self.atlas_format {
+            config.atlas_format = AtlasFormat::parse(format)?;
+        }
+        Ok(config)
+    }
 }
 
 fn main() {
     let args = Cli::parse();
+    let config = args.config().unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        std::process::exit(2);
+    });
 
     match args.command {
-        Commands::Table => run_table(),
-        Commands::Database { lang, write } => run_database(lang, write),
+        Commands::Table => gen::write_table_csvs(&config),
+        Commands::Database { write, version } => run_database(config, write, version),
+        Commands::Lint { built, json, deny } => run_lint(config, built, json, deny),
     }
 }
 
-fn read_db() -> JsonValue {
-    let s = std::fs::read_to_string("dump/db.json").unwrap();
-    json::parse(s.as_str()).unwrap()
-}
+fn run_database(config: Config, write: bool, version: String) {
+    let pipeline = Pipeline::new(config);
+    pipeline.write_term_csvs();
+
+    let mut failed = vec![];
+    pipeline.generate(&version, |lang, database| {
+        // Broken formatting rules are reported for every language before giving up
+        let panics = lint::lint_database(&database)
+            .into_iter()
+            .filter(|f| f.issue.kind() == "panic")
+            .collect::<Vec<_>>();
+        if !panics.is_empty() {
+            for finding in &panics {
+                println!("{}: {}", lang, finding);
+            }
+            failed.push(lang.to_string());
+            return;
+        }
 
-fn run_table() {
-    let db = read_db();
-    for meta in db["Metas"].members() {
-        let table: Table<UnknownTable> = Table::new(meta.to_owned());
-        table.to_csv(std::io::BufWriter::new(
-            std::fs::File::create(format!("dump/table/{}.csv", table.name())).unwrap(),
-        ));
+        if write {
+            pipeline.write(lang, &database);
+        }
+    });
+    if !failed.is_empty() {
+        eprintln!("formatting failed for: {}", failed.join(", "));
+        std::process::exit(1);
+    }
+    if write {
+        pipeline.write_atlas();
     }
 }
 
-fn run_database(lang: String, write: bool) {
-    let mut act_table: Option<Table<ActTable>> = None;
-    let mut act_node_table: Option<Table<ActNodeTable>> = None;
-    let mut skill_table: Option<Table<SkillTable>> = None;
-    let mut skill_mode_table: Option<Table<SkillModeTable>> = None;
-    let mut sm_act_table: Option<Table<SmActTable>> = None;
-    let mut state_table: Option<Table<StateTable>> = None;
-    let mut enemy_table: Option<Table<EnemyTable>> = None;
-    let mut rune_table: Option<Table<RuneTable>> = None;
-
-    let db = read_db();
-    for meta in db["Metas"].members() {
-        let name = meta["Name"].as_str().unwrap();
-        match name {
-            "act" => act_table = Some(Table::new(meta.to_owned())),
-            "act_node" => act_node_table = Some(Table::new(meta.to_owned())),
-            "skill" => skill_table = Some(Table::new(meta.to_owned())),
-            "skill_mode" => skill_mode_table = Some(Table::new(meta.to_owned())),
-            "sm_act" => sm_act_table = Some(Table::new(meta.to_owned())),
-            "state" => state_table = Some(Table::new(meta.to_owned())),
-            "enemy" => enemy_table = Some(Table::new(meta.to_owned())),
-            "rune" => rune_table = Some(Table::new(meta.to_owned())),
-            _ => (),
+fn run_lint(config: Config, built: bool, json: Option<String>, deny: bool) {
+    let mut reports = vec![];
+    let mut push = |lang: &str, database: &data::Database| {
+        reports.push(lint::Report {
+            lang: lang.to_string(),
+            findings: lint::lint_database(database),
+        })
+    };
+    if built {
+        for lang in &config.languages {
+            push(lang, &gen::read_database(&config.i18n(lang)));
         }
-    }
-
-    let skill_table = skill_table.unwrap();
-    let skill_mode_table = skill_mode_table.unwrap();
-    let sm_act_table = sm_act_table.unwrap();
-    let act_table = act_table.unwrap();
-    let act_node_table = act_node_table.unwrap();
-    let enemy_table = enemy_table.unwrap();
-    let rune_table = rune_table.unwrap();
-
-    let states = state_repository_from_dump(&state_table.unwrap());
-    let terms_i18n = terms::term_repository_from_dump();
-
-    let langs = if lang == "all" {
-        LANGUAGES.to_vec()
     } else {
-        vec![lang.as_str()]
-    };
-    for lang in langs {
-        let terms = terms_i18n.get(lang).unwrap();
-
-        let global = global::process_global(&terms);
-        let skill = skill::process_skill(
-            &skill_table,
-            &skill_mode_table,
-            &sm_act_table,
-            &act_table,
-            &act_node_table,
-            &enemy_table,
-            terms,
-            &states,
-        );
-        let rune = rune::process_rune(&rune_table, terms);
-
-        let database = data::Database {
-            global,
-            term: Rc::new(terms.clone()),
-            skill: Rc::new(skill),
-            rune: Rc::new(rune),
-        };
+        Pipeline::new(config).generate("", |lang, database| push(lang, &database));
+    }
 
-        if write {
-            let mut writer = std::io::BufWriter::new(
-                std::fs::File::create(format!("public/i18n/{}/database.msgpack", lang)).unwrap(),
-            );
-            database.write(&mut writer).unwrap();
-        }
+    lint::print_summary(&reports);
+    match json.as_deref() {
+        Some("-") => println!("{}", lint::to_json(&reports).pretty(2)),
+        Some(path) => std::fs::write(path, lint::to_json(&reports).pretty(2)).unwrap(),
+        None => (),
+    }
+    if deny && reports.iter().any(|r| !r.findings.is_empty()) {
+        std::process::exit(1);
     }
 }
@@ -0,0 +1,297 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use std::rc::Rc;
+
+use json::JsonValue;
+
+use atlas::{pack, Atlas, MAX_SIZE};
+use config::Config;
+use data::state::StateRepository;
+use data::term::TermRepository;
+use data::{ChunkEntry, Database, DatabaseChunk, DatabaseIndex, Manifest, SearchIndexes};
+use global::process_global;
+use idhash::IdHash;
+use rune::process_rune;
+use skill::process_skill;
+use sprite::{parse_sprite, Textures};
+use state::state_repository_from_dump;
+use table::act::ActTable;
+use table::act_node::ActNodeTable;
+use table::enemy::EnemyTable;
+use table::rune::RuneTable;
+use table::skill::SkillTable;
+use table::skill_mode::SkillModeTable;
+use table::sm_act::SmActTable;
+use table::state::StateTable;
+use table::{Table, UnknownTable};
+use terms::{term_repositories, term_texts};
+
+/// The dump of the game loaded in memory, to generate the database of each language.
+pub struct Pipeline {
+    config: Config,
+    skill_table: Table<SkillTable>,
+    skill_mode_table: Table<SkillModeTable>,
+    sm_act_table: Table<SmActTable>,
+    act_table: Table<ActTable>,
+    act_node_table: Table<ActNodeTable>,
+    enemy_table: Table<EnemyTable>,
+    rune_table: Table<RuneTable>,
+    states: StateRepository,
+    /// The ids of the states and enemies, for the search indexes.
+    state_ids: BTreeSet<String>,
+    enemy_ids: BTreeSet<String>,
+    term_texts: Vec<(&'static str, Vec<(String, String)>)>,
+    terms: HashMap<&'static str, TermRepository>,
+    /// The icons of the listed skills and runes.
+    atlas: Atlas,
+}
+
+fn read_to_string(path: &std::path::Path) -> String {
+    std::fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+fn read_db(config: &Config) -> JsonValue {
+    json::parse(&read_to_string(&config.db_json())).unwrap()
+}
+
+/// Writes every table of `db.json` as CSV into `<dump>/table`, for debugging.
+pub fn write_table_csvs(config: &Config) {
+    for meta in read_db(config)["Metas"].members() {
+        let table: Table<UnknownTable> = Table::new(meta.to_owned());
+        table.to_csv(std::io::BufWriter::new(
+            std::fs::File::create(config.table_csv(table.name())).unwrap(),
+        ));
+    }
+}
+
+/// Writes the database and its search indexes into `dir` as a manifest, an index and
+/// chunks, removing the files of the previous database.
+pub fn write_database(dir: &Path, database: &Database, indexes: &SearchIndexes) {
+    std::fs::create_dir_all(dir).unwrap();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy();
+        if name.ends_with(".msgpack") {
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
+
+    let hasher = IdHash::new(0, 32);
+    let write = |prefix: &str, bytes: Vec<u8>| {
+        let file = format!("{}-{:08x}.msgpack", prefix, hasher.id_hash(&bytes));
+        std::fs::write(dir.join(&file), bytes).unwrap();
+        file
+    };
+
+    let mut bytes = vec![];
+    database.index().write(&mut bytes).unwrap();
+    let index = write("index", bytes);
+
+    let mut bytes = vec![];
+    indexes.write(&database.version, &mut bytes).unwrap();
+    let search = write("search", bytes);
+
+    let chunks = database
+        .chunks()
+        .into_iter()
+        .map(|(name, chunk)| {
+            let mut bytes = vec![];
+            chunk.write(&database.version, &mut bytes).unwrap();
+            let file = write(&format!("chunk-{}", name), bytes);
+            ChunkEntry { name, file }
+        })
+        .collect::<Vec<_>>();
+
+    let manifest = Manifest {
+        version: manifest_version(&index, &search, &chunks),
+        index,
+        search,
+        chunks,
+    };
+    let mut writer =
+        std::io::BufWriter::new(std::fs::File::create(dir.join("manifest.msgpack")).unwrap());
+    manifest.write(&mut writer).unwrap();
+}
+
+/// The version of a manifest, a hash of the names of its files. The names contain a hash
+/// of the content, so the version changes with any file, even for the same game version.
+pub fn manifest_version(index: &str, search: &str, chunks: &[ChunkEntry]) -> String {
+    let files = [index, search]
+        .iter()
+        .cloned()
+        .chain(chunks.iter().map(|c| c.file.as_str()))
+        .collect::<Vec<_>>();
+    format!("{:08x}", IdHash::new(0, 32).id_hash(&files))
+}
+
+/// Reads the database written by `write_database` back.
+pub fn read_database(dir: &Path) -> Database {
+    let open = |file: &str| {
+        let path = dir.join(file);
+        std::io::BufReader::new(
+            std::fs::File::open(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e)),
+        )
+    };
+    let manifest = Manifest::read(open("manifest.msgpack")).unwrap();
+    let index = DatabaseIndex::read(open(&manifest.index)).unwrap();
+    let chunks = manifest
+        .chunks
+        .iter()
+        .map(|c| DatabaseChunk::read(open(&c.file)).unwrap());
+    Database::from_chunks(&index, chunks)
+}
+
+impl Pipeline {
+    /// Reads the tables of `db.json` and the terms of `I2Languages.asset`, and packs the
+    /// icons into the atlas.
+    pub fn new(config: Config) -> Pipeline {
+        let db = read_db(&config);
+
+        let mut act_table: Option<Table<ActTable>> = None;
+        let mut act_node_table: Option<Table<ActNodeTable>> = None;
+        let mut skill_table: Option<Table<SkillTable>> = None;
+        let mut skill_mode_table: Option<Table<SkillModeTable>> = None;
+        let mut sm_act_table: Option<Table<SmActTable>> = None;
+        let mut state_table: Option<Table<StateTable>> = None;
+        let mut enemy_table: Option<Table<EnemyTable>> = None;
+        let mut rune_table: Option<Table<RuneTable>> = None;
+
+        for meta in db["Metas"].members() {
+            let name = meta["Name"].as_str().unwrap();
+            match name {
+                "act" => act_table = Some(Table::new(meta.to_owned())),
+                "act_node" => act_node_table = Some(Table::new(meta.to_owned())),
+                "skill" => skill_table = Some(Table::new(meta.to_owned())),
+                "skill_mode" => skill_mode_table = Some(Table::new(meta.to_owned())),
+                "sm_act" => sm_act_table = Some(Table::new(meta.to_owned())),
+                "state" => state_table = Some(Table::new(meta.to_owned())),
+                "enemy" => enemy_table = Some(Table::new(meta.to_owned())),
+                "rune" => rune_table = Some(Table::new(meta.to_owned())),
+                _ => (),
+            }
+        }
+
+        let states = state_repository_from_dump(&state_table.unwrap());
+        let state_ids = states
+            .values()
+            .map(|s| s.id.clone())
+            .collect::<BTreeSet<_>>();
+        let enemy_table = enemy_table.unwrap();
+        let enemy_ids = enemy_table
+            .iter()
+            .map(|e| e.id.clone())
+            .collect::<BTreeSet<_>>();
+
+        let term_texts = term_texts(&read_to_string(&config.i2_languages()));
+        let terms = term_repositories(&term_texts);
+
+        let skill_table = skill_table.unwrap();
+        let skill_mode_table = skill_mode_table.unwrap();
+        let rune_table = rune_table.unwrap();
+        let asset = config.asset();
+        let textures = Textures::from_asset(&asset);
+        let skill_icons = skill_table
+            .iter()
+            .filter(|s| s.is_listed())
+            .flat_map(|s| skill_mode_table.iter().filter(move |m| s.has_mode(m)))
+            .map(|m| &m.icon);
+        let rune_icons = rune_table.iter().filter(|r| r.is_listed()).map(|r| &r.icon);
+        let sources = skill_icons
+            .chain(rune_icons)
+            .map(|icon| (icon.clone(), parse_sprite(&asset, &textures, icon)))
+            .collect();
+        let atlas = pack(sources, MAX_SIZE);
+
+        Pipeline {
+            config,
+            skill_table,
+            skill_mode_table,
+            sm_act_table: sm_act_table.unwrap(),
+            act_table: act_table.unwrap(),
+            act_node_table: act_node_table.unwrap(),
+            enemy_table,
+            rune_table,
+            states,
+            state_ids,
+            enemy_ids,
+            term_texts,
+            terms,
+            atlas,
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Generates the database of `lang`.
+    pub fn database(&self, lang: &str, version: &str) -> Database {
+        let terms = self
+            .terms
+            .get(lang)
+            .unwrap_or_else(|| panic!("unknown language: {}", lang));
+
+        let global = process_global(terms, &self.state_ids, &self.enemy_ids);
+        let skill = process_skill(
+            &self.skill_table,
+            &self.skill_mode_table,
+            &self.sm_act_table,
+            &self.act_table,
+            &self.act_node_table,
+            &self.enemy_table,
+            terms,
+            &self.states,
+            &self.atlas,
+        );
+        let rune = process_rune(&self.rune_table, terms, &self.atlas);
+
+        Database {
+            global,
+            term: Rc::new(terms.clone()),
+            skill: Rc::new(skill),
+            rune: Rc::new(rune),
+            version: version.to_string(),
+        }
+    }
+
+    /// Generates the database of each configured language.
+    pub fn generate<F: FnMut(&str, Database)>(&self, version: &str, mut f: F) {
+        for lang in &self.config.languages {
+            f(lang, self.database(lang, version));
+        }
+    }
+
+    pub fn search_indexes(&self, database: &Database) -> SearchIndexes {
+        SearchIndexes::build(database, &self.state_ids, &self.enemy_ids)
+    }
+
+    /// Writes the database of `lang` and its search indexes into the output.
+    pub fn write(&self, lang: &str, database: &Database) {
+        write_database(
+            &self.config.i18n(lang),
+            database,
+            &self.search_indexes(database),
+        );
+    }
+
+    /// Writes the atlases of the icons, with their `sprites.css`, into the output.
+    pub fn write_atlas(&self) {
+        self.atlas
+            .write(&self.config.output, self.config.atlas_format);
+    }
+
+    /// Writes the terms of each configured language as CSV into the dump, for debugging.
+    pub fn write_term_csvs(&self) {
+        for (lang, texts) in &self.term_texts {
+            if !self.config.languages.contains(lang) {
+                continue;
+            }
+            let file_writer =
+                std::io::BufWriter::new(std::fs::File::create(self.config.term_csv(lang)).unwrap());
+            let mut csv_writer = csv::Writer::from_writer(file_writer);
+            for (key, value) in texts.iter() {
+                csv_writer.write_record([key, value]).unwrap();
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
+use atlas::Atlas;
 use data::term::TermRepository;
 use data::{Rune, RuneRepository};
 use idhash::IdHash;
-use sprite::parse_icon;
 use std::convert::TryInto;
 use std::hash::{Hash, Hasher};
 use table::rune::RuneTable;
@@ -15,16 +15,20 @@ impl Hash for RuneWrapper {
     }
 }
 
-pub fn process_rune(rune_table: &Table<RuneTable>, terms: &TermRepository) -> RuneRepository {
+pub fn process_rune(
+    rune_table: &Table<RuneTable>,
+    terms: &TermRepository,
+    atlas: &Atlas,
+) -> RuneRepository {
     let mut runes = rune_table
         .iter()
         .flat_map(|rune_row| {
-            if rune_row.enable.is_empty() && rune_row.in_dict {
+            if rune_row.is_listed() {
                 let rune = Rune {
                     hash: 0,
                     id: rune_row.id.clone(),
                     order: rune_row.order.try_into().unwrap(),
-                    icon: parse_icon(&rune_row.icon),
+                    icon: atlas.sprite(&rune_row.icon),
                     rarity: rune_row.rarity.try_into().unwrap(),
                     name: terms.get_str(&format!("NM-{}", rune_row.id)),
                     description: terms.get(&format!("DC-{}", rune_row.id)),
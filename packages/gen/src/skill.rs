@@ -2,6 +2,7 @@ use std::convert::TryInto;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
+use atlas::Atlas;
 use data::skill::{
     Act, ActNode, ActTrigger, AvoidType, ParamKey, Reduce, Skill, SkillCategory, SkillMode,
     SkillRepository, Target,
@@ -10,7 +11,6 @@ use data::state::StateRepository;
 use data::term::TermRepository;
 use data::token::{Token, Tokens};
 use idhash::IdHash;
-use sprite::parse_icon;
 use table::act::ActTable;
 use table::act_node::{ActNodeRow, ActNodeTable};
 use table::enemy::EnemyTable;
@@ -40,13 +40,15 @@ pub fn process_skill(
     enemy_table: &Table<EnemyTable>,
     terms: &TermRepository,
     states: &StateRepository,
+    atlas: &Atlas,
 ) -> SkillRepository {
     let mut skills = skill_table
         .iter()
-        .flat_map(|skill_row| {
+        .filter(|skill_row| skill_row.is_listed())
+        .map(|skill_row| {
             let mode_rows = skill_mode_table
                 .iter()
-                .filter(|row| row.skill == format!("{}_{}", skill_row.name, skill_row.row_id))
+                .filter(|row| skill_row.has_mode(row))
                 .collect::<Vec<_>>();
 
             let modes = mode_rows
@@ -61,6 +63,7 @@ pub fn process_skill(
                         enemy_table,
                         terms,
                         states,
+                        atlas,
                     )
                 })
                 .collect::<Vec<_>>();
@@ -69,24 +72,20 @@ pub fn process_skill(
 
             let name_id = modes[0].id.clone();
 
-            if skill_row.enable.is_empty() && skill_row.in_dict {
-                let skill = Skill {
-                    hash: 0,
-                    id: skill_row.id.to_string(),
-                    modes,
-                    category: SkillCategory::from_str(skill_row.category.as_str()).unwrap(),
-                    rarity: skill_row.rarity.try_into().unwrap(),
-                    in_dictionary: skill_row.in_dict,
-                    is_free: skill_row.is_free,
-                    name: terms.get_str(&format!("NM-{}", name_id)),
-                };
-                Some(SkillIdOrder {
-                    skill,
-                    id: skill_row.id.clone(),
-                    order: skill_row.order,
-                })
-            } else {
-                None
+            let skill = Skill {
+                hash: 0,
+                id: skill_row.id.to_string(),
+                modes,
+                category: SkillCategory::from_str(skill_row.category.as_str()).unwrap(),
+                rarity: skill_row.rarity.try_into().unwrap(),
+                in_dictionary: skill_row.in_dict,
+                is_free: skill_row.is_free,
+                name: terms.get_str(&format!("NM-{}", name_id)),
+            };
+            SkillIdOrder {
+                skill,
+                id: skill_row.id.clone(),
+                order: skill_row.order,
             }
         })
         .collect::<Vec<_>>();
@@ -113,6 +112,7 @@ fn process_skill_mode(
     enemy_table: &Table<EnemyTable>,
     terms: &TermRepository,
     states: &StateRepository,
+    atlas: &Atlas,
 ) -> SkillMode {
     let sm_act_rows = sm_act_table
         .iter()
@@ -183,7 +183,7 @@ fn process_skill_mode(
 
     SkillMode {
         id: mode_row.id.to_string(),
-        icon: parse_icon(&mode_row.icon),
+        icon: atlas.sprite(&mode_row.icon),
         is_alt: mode_row.alt_mode,
         is_brave: mode_row.is_brave,
         use_num: mode_row.use_num.try_into().unwrap(),
@@ -556,13 +556,6 @@ fn process_act_node(
         }
     };
 
-    for t in description.vec() {
-        match t {
-            Token::Panic(s) => panic!("panic: {}", s),
-            _ => (),
-        }
-    }
-
     ActNode {
         id: act_node_row.id.to_string(),
         action_type: act_node_row.action_type.to_string(),
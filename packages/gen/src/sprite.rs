@@ -1,35 +1,80 @@
-use data::Sprite;
+use std::collections::HashMap;
 use std::convert::TryInto;
+use std::path::{Path, PathBuf};
+
 use yaml_rust::{Yaml, YamlLoader};
 
-pub fn parse_icon(name: &str) -> Sprite {
-    let path = format!("dump/asset/ExportedProject/Assets/Sprite/{}.asset", name);
-    let s = std::fs::read_to_string(path).unwrap();
+fn load_yaml(path: &Path) -> Yaml {
+    let s = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
     let docs = YamlLoader::load_from_str(s.as_str()).unwrap();
-    let doc = &docs[0];
-    let texture = &doc["Sprite"]["m_RD"]["texture"];
-    let index = match texture["guid"].as_str().unwrap() {
-        "62fd6711ce5ab1d42bbd93655ec0ab0c" => 1,
-        "eb9d7fa2dbefb314ebcdfa2b4a55d666" => 2,
-        "ebccdba04e8244a43927c6ee18b5eb33" => 3,
-        id @ _ => panic!("invalid guid: {:?}", id),
-    };
-    // assert_eq!(
-    //     texture["guid"].as_str().unwrap(),
-    //     "ebccdba04e8244a43927c6ee18b5eb33"
-    // );
-    let texture_height = 4096;
+    docs.into_iter().next().unwrap()
+}
+
+/// The textures of the assets by GUID, read from the `.meta` files next to them.
+pub struct Textures {
+    paths: HashMap<String, PathBuf>,
+}
+
+impl Textures {
+    pub fn from_asset(asset: &Path) -> Textures {
+        fn visit(dir: &Path, paths: &mut HashMap<String, PathBuf>) {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                return;
+            };
+            for entry in entries {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    visit(&path, paths);
+                } else if path.extension().is_some_and(|e| e == "meta") {
+                    let texture = path.with_extension("");
+                    if let Some(guid) = load_yaml(&path)["guid"].as_str() {
+                        paths.insert(guid.to_string(), texture);
+                    }
+                }
+            }
+        }
+
+        let mut paths = HashMap::new();
+        visit(&asset.join("Texture2D"), &mut paths);
+        Textures { paths }
+    }
+
+    pub fn get(&self, guid: &str) -> Option<&Path> {
+        self.paths.get(guid).map(|p| p.as_path())
+    }
+}
+
+/// A sprite of a texture, in pixels from the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpriteSource {
+    pub texture: PathBuf,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Reads the sprite `name` of the assets, whose texture is found by its GUID.
+pub fn parse_sprite(asset: &Path, textures: &Textures, name: &str) -> SpriteSource {
+    let doc = load_yaml(&asset.join(format!("Sprite/{}.asset", name)));
+    let guid = doc["Sprite"]["m_RD"]["texture"]["guid"].as_str().unwrap();
+    let texture = textures
+        .get(guid)
+        .unwrap_or_else(|| panic!("texture of sprite {} not found: {}", name, guid));
+    let (_, texture_height) = image::image_dimensions(texture).unwrap();
+
     let rect = &doc["Sprite"]["m_Rect"];
-    let x: Result<u16, _> = parse_number(&rect["x"]).try_into();
-    let y: Result<u16, _> = parse_number(&rect["y"]).try_into();
-    let width: Result<u8, _> = parse_number(&rect["width"]).try_into();
-    let height: Result<u8, _> = parse_number(&rect["height"]).try_into();
-    Sprite {
-        index,
-        x: x.unwrap(),
-        y: texture_height - y.unwrap() - height.unwrap() as u16,
-        width: width.unwrap(),
-        height: height.unwrap(),
+    let x = parse_number(&rect["x"]).try_into().unwrap();
+    let y: u32 = parse_number(&rect["y"]).try_into().unwrap();
+    let width = parse_number(&rect["width"]).try_into().unwrap();
+    let height: u32 = parse_number(&rect["height"]).try_into().unwrap();
+    SpriteSource {
+        texture: texture.to_path_buf(),
+        x,
+        // Unity measures from the bottom
+        y: texture_height - y - height,
+        width,
+        height,
     }
 }
 
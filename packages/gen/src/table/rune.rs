@@ -25,6 +25,13 @@ pub struct RuneRow {
     pub enable: String,
 }
 
+impl RuneRow {
+    /// Whether the rune is shown in the database.
+    pub fn is_listed(&self) -> bool {
+        self.enable.is_empty() && self.in_dict
+    }
+}
+
 impl TableParser for RuneTable {
     type Row = RuneRow;
     fn parse_row(p: &EntityParser) -> Self::Row {
@@ -1,3 +1,4 @@
+use table::skill_mode::SkillModeRow;
 use table::{EntityParser, TableParser};
 
 pub struct SkillTable;
@@ -29,6 +30,18 @@ pub struct SkillRow {
     pub enable: String,
 }
 
+impl SkillRow {
+    /// Whether the skill is shown in the database.
+    pub fn is_listed(&self) -> bool {
+        self.enable.is_empty() && self.in_dict
+    }
+
+    /// Whether the skill mode `row` belongs to this skill.
+    pub fn has_mode(&self, row: &SkillModeRow) -> bool {
+        row.skill == format!("{}_{}", self.name, self.row_id)
+    }
+}
+
 impl TableParser for SkillTable {
     type Row = SkillRow;
     fn parse_row(p: &EntityParser) -> Self::Row {
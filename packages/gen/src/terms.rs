@@ -10,11 +10,10 @@ use data::LANGUAGES;
 
 use crate::data::token::Token;
 
-pub fn term_repository_from_dump() -> HashMap<&'static str, TermRepository> {
-    let s =
-        std::fs::read_to_string("dump/asset/ExportedProject/Assets/Resources/I2Languages.asset")
-            .unwrap();
-    let docs = YamlLoader::load_from_str(s.as_str()).unwrap();
+/// The texts of each language in an `I2Languages.asset`, with the references to other
+/// terms substituted.
+pub fn term_texts(s: &str) -> Vec<(&'static str, Vec<(String, String)>)> {
+    let docs = YamlLoader::load_from_str(s).unwrap();
     let doc = &docs[0];
     let terms = doc["MonoBehaviour"]["mSource"]["mTerms"].as_vec().unwrap();
 
@@ -41,7 +40,7 @@ pub fn term_repository_from_dump() -> HashMap<&'static str, TermRepository> {
         }
     }
 
-    let mut repos = HashMap::new();
+    let mut texts = vec![];
 
     let re = Regex::new(r"\{\[(.+?)]}").unwrap();
     for (i, lang) in LANGUAGES.iter().enumerate() {
@@ -62,17 +61,18 @@ pub fn term_repository_from_dump() -> HashMap<&'static str, TermRepository> {
             new_out.push((key.clone(), n.to_string()));
         }
 
-        {
-            let file_writer = std::io::BufWriter::new(
-                std::fs::File::create(format!("dump/{}.csv", lang)).unwrap(),
-            );
-            let mut csv_writer = csv::Writer::from_writer(file_writer);
-            for (key, value) in new_out.iter() {
-                csv_writer.write_record(&[key, &value]).unwrap();
-            }
-        }
+        texts.push((*lang, new_out));
+    }
+
+    texts
+}
 
-        let nodes = new_out
+pub fn term_repositories(
+    texts: &[(&'static str, Vec<(String, String)>)],
+) -> HashMap<&'static str, TermRepository> {
+    let mut repos = HashMap::new();
+    for (lang, texts) in texts {
+        let nodes = texts
             .iter()
             .map(|(key, value)| {
                 (
@@ -84,7 +84,7 @@ pub fn term_repository_from_dump() -> HashMap<&'static str, TermRepository> {
             })
             .collect::<Vec<_>>();
 
-        repos.insert(*lang, TermRepository::from_vec(nodes.clone()));
+        repos.insert(*lang, TermRepository::from_vec(nodes));
     }
 
     repos
@@ -109,7 +109,8 @@ fn parse(s: &str) -> Vec<Token> {
         if start < end {
             let span = &s[start..end];
             if span.starts_with('<') {
-                tokens.push(Token::Var(s[start + 1..end - 1].to_string()));
+                let tag = &s[start + 1..end - 1];
+                tokens.push(Token::from_rich_text_tag(tag).unwrap_or(Token::Var(tag.to_string())));
             } else if span.starts_with('{') {
                 tokens.push(Token::Var(s[start + 1..end - 1].to_string()));
             } else if span == "__" {
@@ -128,6 +129,7 @@ mod test {
     use terms::parse;
 
     use super::Token;
+    use data::token::Style;
 
     #[test]
     fn test_parse() {
@@ -178,5 +180,17 @@ mod test {
                 Token::Var("jkl".to_string())
             ]
         );
+        assert_eq!(
+            parse("<color=#FB0>100<sprite name=gs></color><b>!</b>"),
+            vec![
+                Token::StyleStart(Style::Color("#FB0".to_string())),
+                Token::Text("100".to_string()),
+                Token::Var("sprite name=gs".to_string()),
+                Token::StyleEnd,
+                Token::StyleStart(Style::Bold),
+                Token::Text("!".to_string()),
+                Token::StyleEnd
+            ]
+        );
     }
 }
@@ -0,0 +1,46 @@
+//! The chunked database written into the output reads back as generated.
+
+extern crate data;
+extern crate gen;
+
+use std::path::Path;
+
+use gen::{read_database, Config, Pipeline};
+
+#[test]
+fn test_write_read_database() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let output = std::env::temp_dir().join(format!("gen-test-database-{}", std::process::id()));
+    let pipeline = Pipeline::new(Config {
+        dump: fixtures,
+        asset: None,
+        output: output.clone(),
+        languages: vec!["ja"],
+        ..Config::default()
+    });
+
+    let database = pipeline.database("ja", "1.0.0");
+    pipeline.write("ja", &database);
+    let dir = pipeline.config().i18n("ja");
+    let read = read_database(&dir);
+    std::fs::remove_dir_all(&output).unwrap();
+
+    assert!(read == database);
+    assert!(read.index() == database.index());
+    let chunks = database
+        .chunks()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        chunks,
+        vec![
+            "skill-summon",
+            "skill-attack",
+            "skill-support",
+            "skill-special",
+            "rune",
+            "term"
+        ]
+    );
+}
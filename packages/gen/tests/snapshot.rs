@@ -0,0 +1,168 @@
+//! Golden tests of the formatting rules. The databases generated from the synthetic dump
+//! in `tests/fixtures` are rendered per language and compared to `tests/snapshots`.
+//! Run with `UPDATE_SNAPSHOTS=1` to write the snapshots again after an intended change.
+
+extern crate data;
+extern crate gen;
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use data::description::{DescriptionTree, Renderer};
+use data::token::{Style, Tokens};
+use data::Database;
+
+use gen::{Config, Pipeline};
+
+/// Japanese and English as written, French partly translated and Chinese falling back to
+/// Japanese.
+const LANGUAGES: [&str; 4] = ["ja", "en", "fr", "zh-CN"];
+
+fn tests_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+/// Plain text, with the terms explained in tooltips as `[text|tips]`, unresolved
+/// variables as `<name>`, errors as `!text!` and styles as rich text tags.
+#[derive(Default)]
+struct SnapshotRenderer {
+    out: String,
+}
+
+impl Renderer for SnapshotRenderer {
+    fn text(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    fn new_line(&mut self) {
+        self.out.push('\n');
+    }
+
+    fn indent(&mut self) {
+        self.out.push_str("\n  ");
+    }
+
+    fn var(&mut self, name: &str) {
+        let _ = write!(self.out, "<{}>", name);
+    }
+
+    fn error(&mut self, text: &str) {
+        let _ = write!(self.out, "!{}!", text);
+    }
+
+    fn term_start(&mut self, _name: &str, tips: Option<&str>) {
+        if tips.is_some() {
+            self.out.push('[');
+        }
+    }
+
+    fn term_end(&mut self, _name: &str, tips: Option<&str>) {
+        if let Some(tips) = tips {
+            let _ = write!(self.out, "|{}]", tips);
+        }
+    }
+
+    fn style_start(&mut self, style: &Style) {
+        let _ = match style {
+            Style::Bold => write!(self.out, "<b>"),
+            Style::Italic => write!(self.out, "<i>"),
+            Style::Underline => write!(self.out, "<u>"),
+            Style::Strikethrough => write!(self.out, "<s>"),
+            Style::Color(color) => write!(self.out, "<color={}>", color),
+            Style::Size(size) => write!(self.out, "<size={}>", size),
+        };
+    }
+
+    fn style_end(&mut self, _style: &Style) {
+        self.out.push_str("</>");
+    }
+}
+
+fn render_tokens(out: &mut String, tokens: &Tokens) {
+    let mut renderer = SnapshotRenderer::default();
+    DescriptionTree::new(tokens).render(&mut renderer);
+    let _ = writeln!(out, "{}", renderer.out.trim_end());
+}
+
+fn render(database: &Database) -> String {
+    let mut out = String::new();
+    for skill in database.skill.iter() {
+        let _ = writeln!(
+            out,
+            "# skill {} {} ({}, rarity {})",
+            skill.id, skill.name, skill.category, skill.rarity
+        );
+        for mode in &skill.modes {
+            let _ = writeln!(out, "## {} {}", mode.id, mode.name);
+            render_tokens(&mut out, &mode.format());
+        }
+        out.push('\n');
+    }
+    for rune in database.rune.iter() {
+        let _ = writeln!(
+            out,
+            "# rune {} {} (rarity {})",
+            rune.id, rune.name, rune.rarity
+        );
+        render_tokens(&mut out, &rune.format());
+        out.push('\n');
+    }
+    let _ = writeln!(out, "# rarity colors {:?}", database.global.rarity_colors);
+    out
+}
+
+/// The first line which differs, to find the change in a long snapshot.
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (e, a) => {
+                return format!(
+                    "line {}:\n  expected: {}\n    actual: {}",
+                    line,
+                    e.unwrap_or("<end>"),
+                    a.unwrap_or("<end>")
+                )
+            }
+        }
+    }
+}
+
+#[test]
+fn test_snapshots() {
+    let fixtures = tests_dir().join("fixtures");
+    let pipeline = Pipeline::new(Config {
+        dump: fixtures.clone(),
+        asset: None,
+        output: fixtures,
+        languages: LANGUAGES.to_vec(),
+        ..Config::default()
+    });
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+
+    let mut failures = vec![];
+    pipeline.generate("", |lang, database| {
+        let path = tests_dir().join(format!("snapshots/{}.txt", lang));
+        let actual = render(&database);
+        if update {
+            std::fs::write(&path, actual).unwrap();
+            return;
+        }
+        let expected = std::fs::read_to_string(&path).unwrap_or_default();
+        if actual != expected {
+            failures.push(format!(
+                "{}: {}",
+                lang,
+                first_difference(&expected, &actual)
+            ));
+        }
+    });
+    assert!(
+        failures.is_empty(),
+        "snapshots differ, run with UPDATE_SNAPSHOTS=1 if intended\n{}",
+        failures.join("\n")
+    );
+}
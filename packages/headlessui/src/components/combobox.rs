@@ -0,0 +1,377 @@
+use dioxus::dioxus_core::AttributeValue;
+use dioxus::prelude::*;
+use dioxus::web::WebEventExt;
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::components::{count_by_role, navigate, scroll_into_view, use_id, RenderFn};
+
+#[derive(Clone)]
+struct ComboboxState {
+    id: String,
+    open: Signal<bool>,
+    active: Signal<Option<usize>>,
+    on_change: EventHandler<usize>,
+}
+
+impl ComboboxState {
+    fn options_id(&self) -> String {
+        format!("{}-options", self.id)
+    }
+
+    fn option_id(&self, index: usize) -> String {
+        format!("{}-option-{}", self.id, index)
+    }
+
+    fn set_active(&mut self, index: Option<usize>) {
+        *self.active.write() = index;
+        if let Some(index) = index {
+            scroll_into_view(&self.option_id(index));
+        }
+    }
+
+    fn navigate(&mut self, key: &str) {
+        let count = count_by_role(&self.options_id(), "option");
+        if let Some(active) = navigate(key, *self.active.peek(), count) {
+            self.set_active(active);
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        *self.open.write() = false;
+        *self.active.write() = None;
+        self.on_change.call(index);
+    }
+}
+
+pub struct ComboboxRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The main Combobox component. Options are identified by their index.
+#[component]
+pub fn Combobox(
+    /// Called with the index of the option selected by click or the Enter key.
+    on_change: EventHandler<usize>,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<ComboboxRenderArgs>>,
+    children: Element,
+) -> Element {
+    let id = use_id("combobox");
+    let state = use_signal(|| ComboboxState {
+        id,
+        open: Signal::new(false),
+        active: Signal::new(None),
+        on_change,
+    });
+    let _ = use_context_provider(|| state());
+
+    if let Some(render) = render {
+        render.call(ComboboxRenderArgs {
+            attrs: attributes,
+            children,
+            open: (state.read().open)(),
+        })
+    } else {
+        rsx! {
+            div {
+                ..attributes,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct ComboboxInputRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub open: bool,
+}
+
+/// The input which filters the options. Arrow keys move the active option,
+/// Enter selects it and Escape closes the options.
+#[component]
+pub fn ComboboxInput(
+    /// Called with the text whenever it changes, to filter the options.
+    on_change: Option<EventHandler<String>>,
+    /// Focuses the input once mounted, which `autofocus` doesn't do for inputs
+    /// rendered after the page has loaded.
+    #[props(default = false)]
+    auto_focus: bool,
+    #[props(extends = GlobalAttributes, extends = input)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<ComboboxInputRenderArgs>>,
+) -> Element {
+    let state =
+        try_use_context::<ComboboxState>().expect("ComboboxInput must be a child of Combobox");
+    let open = (state.open)();
+
+    let mut attrs = vec![
+        Attribute::new(
+            "id",
+            AttributeValue::Text(format!("{}-input", state.id)),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "role",
+            AttributeValue::Text("combobox".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-autocomplete",
+            AttributeValue::Text("list".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-controls",
+            AttributeValue::Text(state.options_id()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-expanded",
+            AttributeValue::Text(open.to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "autocomplete",
+            AttributeValue::Text("off".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "oninput",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |event: Event<PlatformEventData>| {
+                    let e: FormEvent = event.map(|e| e.into());
+                    *state.open.write() = true;
+                    *state.active.write() = Some(0);
+                    on_change.as_ref().map(|f| f.call(e.value()));
+                }
+            }),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onkeydown",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |event: Event<PlatformEventData>| {
+                    let e: KeyboardEvent = event.map(|e| e.into());
+                    let web_event = e.web_event();
+                    match web_event.key().as_str() {
+                        key @ ("ArrowDown" | "ArrowUp") => {
+                            web_event.prevent_default();
+                            *state.open.write() = true;
+                            state.navigate(key);
+                        }
+                        "Enter" => {
+                            let active = *state.active.peek();
+                            let count = count_by_role(&state.options_id(), "option");
+                            if let Some(active) = active.filter(|a| *a < count) {
+                                web_event.prevent_default();
+                                state.select(active);
+                            }
+                        }
+                        "Escape" | "Tab" => {
+                            *state.open.write() = false;
+                            *state.active.write() = None;
+                        }
+                        _ => {}
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onblur",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |_: Event<PlatformEventData>| {
+                    *state.open.write() = false;
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    if auto_focus {
+        attrs.push(Attribute::new(
+            "onmounted",
+            AttributeValue::listener(move |event: Event<PlatformEventData>| {
+                let e: MountedEvent = event.map(|e| e.into());
+                if let Some(el) = e.web_event().dyn_ref::<web_sys::HtmlElement>() {
+                    let _ = el.focus();
+                }
+            }),
+            None,
+            false,
+        ));
+    }
+    if let Some(active) = (state.active)() {
+        attrs.push(Attribute::new(
+            "aria-activedescendant",
+            AttributeValue::Text(state.option_id(active)),
+            None,
+            false,
+        ));
+    }
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(ComboboxInputRenderArgs { attrs, open })
+    } else {
+        rsx! {
+            input { ..attrs }
+        }
+    }
+}
+
+pub struct ComboboxOptionsRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The list of options, rendered while the Combobox is open or always when `static`.
+#[component]
+pub fn ComboboxOptions(
+    #[props(default = false)] r#static: bool,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<ComboboxOptionsRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state =
+        try_use_context::<ComboboxState>().expect("ComboboxOptions must be a child of Combobox");
+    let open = (state.open)();
+    if !open && !r#static {
+        return None;
+    }
+
+    let mut attrs = vec![
+        Attribute::new("id", AttributeValue::Text(state.options_id()), None, false),
+        Attribute::new(
+            "role",
+            AttributeValue::Text("listbox".to_string()),
+            None,
+            false,
+        ),
+        // Keep the focus on the input while an option is clicked
+        Attribute::new(
+            "dioxus-prevent-default",
+            AttributeValue::Text("onmousedown".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onmousedown",
+            AttributeValue::listener(move |_: Event<PlatformEventData>| {}),
+            None,
+            false,
+        ),
+    ];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(ComboboxOptionsRenderArgs {
+            attrs,
+            children,
+            open,
+        })
+    } else {
+        rsx! {
+            ul {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct ComboboxOptionRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub active: bool,
+}
+
+/// An option. Indexes must follow the rendering order, starting from 0.
+#[component]
+pub fn ComboboxOption(
+    index: usize,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<ComboboxOptionRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state =
+        try_use_context::<ComboboxState>().expect("ComboboxOption must be a child of Combobox");
+    let active = (state.active)() == Some(index);
+
+    let mut attrs = vec![
+        Attribute::new(
+            "id",
+            AttributeValue::Text(state.option_id(index)),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "role",
+            AttributeValue::Text("option".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-selected",
+            AttributeValue::Text(active.to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onmousemove",
+            AttributeValue::listener({
+                let mut active_index = state.active;
+                move |_: Event<PlatformEventData>| {
+                    if *active_index.peek() != Some(index) {
+                        *active_index.write() = Some(index);
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onclick",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |_: Event<PlatformEventData>| {
+                    state.select(index);
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(ComboboxOptionRenderArgs {
+            attrs,
+            children,
+            active,
+        })
+    } else {
+        rsx! {
+            li {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use dioxus::dioxus_core::AttributeValue;
 use dioxus::prelude::*;
 use dioxus::web::WebEventExt;
-use web_sys::wasm_bindgen::prelude::Closure;
 use web_sys::wasm_bindgen::JsCast;
 
-use crate::components::RenderFn;
+use crate::components::{focus, use_id, RenderFn};
+
+mod stack;
 
 struct DialogState {
+    id: String,
     open: Signal<bool>,
     dialog: Signal<Option<web_sys::HtmlDialogElement>>,
 }
@@ -18,72 +20,80 @@ pub struct DialogRenderArgs {
 }
 
 /// The main Dialog component.
+///
+/// Open dialogs form a stack: only the top one reacts to Escape, Tab cycles through its
+/// elements, the focus returns to where it was once it closes and the page doesn't
+/// scroll while any dialog is open.
 #[component]
 pub fn Dialog(
     /// Whether the Dialog is open or not.
     open: bool,
     /// Called when the Dialog is dismissed (via outside click of the DialogPanel or by pressing the Escape key). Typically used to close the dialog by setting open to false.
     on_close: Option<EventHandler<()>>,
+    /// The id of the element to focus when the Dialog opens. Defaults to its first focusable element.
+    initial_focus: Option<String>,
     #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
     render: Option<RenderFn<DialogRenderArgs>>,
     children: Element,
 ) -> Element {
+    let id = use_id("dialog");
     let state = use_signal(|| DialogState {
+        id,
         open: Signal::new(open),
         dialog: Signal::new(None),
     });
     let _ = use_context_provider(|| state);
+    let id = state.read().id.clone();
 
-    use_effect(move || {
-        if let Some(dialog) = state.read().dialog.read().as_ref() {
-            if *state.read().open.read() {
-                if !dialog.has_attribute("open") {
-                    dialog.show_modal().unwrap();
-                }
-            } else {
-                if dialog.has_attribute("open") {
-                    dialog.close();
-                }
-            }
-        }
-    });
+    let mut open_signal = state.read().open;
+    if *open_signal.peek() != open {
+        *open_signal.write() = open;
+    }
 
-    let on_close2 = on_close.clone();
-    let func = Box::new(move |e: web_sys::KeyboardEvent| {
-        e.prevent_default();
-        if e.key() == "Escape" {
+    use_effect({
+        let id = id.clone();
+        move || {
+            let open = (state.read().open)();
             if let Some(dialog) = state.read().dialog.read().as_ref() {
-                dialog.close();
+                if open {
+                    if !dialog.has_attribute("open") {
+                        stack::push(&id);
+                        dialog.show_modal().unwrap();
+                        if let Some(target) = &initial_focus {
+                            focus(target);
+                        } else if let Some(el) = stack::focusable_elements(dialog).first() {
+                            let _ = el.focus();
+                        } else {
+                            let _ = dialog.focus();
+                        }
+                    }
+                } else {
+                    if dialog.has_attribute("open") {
+                        dialog.close();
+                    }
+                    stack::remove(&id);
+                }
             }
-            on_close2.as_ref().map(|f| f.call(()));
         }
-    }) as Box<dyn FnMut(_)>;
-
-    let handler = Closure::wrap(func);
-
-    let r = use_signal(|| handler);
-    use_effect(move || {
-        web_sys::window()
-            .unwrap()
-            .document()
-            .unwrap()
-            .add_event_listener_with_callback("keydown", r.read().as_ref().unchecked_ref())
-            .unwrap();
     });
 
-    use_drop(move || {
-        web_sys::window()
-            .unwrap()
-            .document()
-            .unwrap()
-            .remove_event_listener_with_callback("keydown", r.read().as_ref().unchecked_ref())
-            .unwrap();
+    use_drop({
+        let id = id.clone();
+        move || stack::remove(&id)
     });
 
     let mut attrs = vec![
+        Attribute::new("id", AttributeValue::Text(id.clone()), None, false),
+        Attribute::new(
+            "tabindex",
+            AttributeValue::Text("-1".to_string()),
+            None,
+            false,
+        ),
         Attribute::new(
             "onclick",
             AttributeValue::listener({
+                let on_close = on_close.clone();
                 move |event: Event<PlatformEventData>| {
                     event.stop_propagation();
                     on_close.as_ref().map(|f| f.call(()));
@@ -92,6 +102,35 @@ pub fn Dialog(
             None,
             false,
         ),
+        Attribute::new(
+            "onkeydown",
+            AttributeValue::listener({
+                let id = id.clone();
+                move |event: Event<PlatformEventData>| {
+                    // Events from a nested dialog bubble up through this one
+                    if !stack::is_top(&id) {
+                        return;
+                    }
+                    let e: KeyboardEvent = event.map(|e| e.into());
+                    let web_event = e.web_event();
+                    match web_event.key().as_str() {
+                        "Escape" => {
+                            // Keep the browser from closing the dialog behind our back
+                            web_event.prevent_default();
+                            on_close.as_ref().map(|f| f.call(()));
+                        }
+                        "Tab" => {
+                            if let Some(dialog) = state.read().dialog.peek().as_ref() {
+                                stack::trap_focus(dialog, web_event);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
         Attribute::new(
             "onmounted",
             AttributeValue::listener(move |event: Event<PlatformEventData>| {
@@ -112,6 +151,12 @@ pub fn Dialog(
             None,
             false,
         ),
+        Attribute::new(
+            "aria-modal",
+            AttributeValue::Text("true".to_string()),
+            None,
+            false,
+        ),
     ];
     attrs.extend(attributes);
     attrs.sort_by_key(|a| a.name);
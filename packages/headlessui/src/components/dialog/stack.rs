@@ -0,0 +1,107 @@
+//! The stack of open dialogs. Only the top dialog handles the keyboard, the focus
+//! returns to where it was when a dialog closes, and the page doesn't scroll while
+//! any dialog is open.
+
+use std::cell::RefCell;
+
+use web_sys::wasm_bindgen::JsCast;
+
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+struct Entry {
+    id: String,
+    restore_focus: Option<web_sys::HtmlElement>,
+}
+
+#[derive(Default)]
+struct Stack {
+    entries: Vec<Entry>,
+    /// The `overflow` of the body before it was locked.
+    body_overflow: Option<String>,
+}
+
+thread_local! {
+    static STACK: RefCell<Stack> = RefCell::default();
+}
+
+fn document() -> Option<web_sys::Document> {
+    web_sys::window()?.document()
+}
+
+fn active_element() -> Option<web_sys::HtmlElement> {
+    document()?.active_element()?.dyn_into().ok()
+}
+
+/// Pushes the dialog `id` on top, remembering the focused element.
+pub(super) fn push(id: &str) {
+    STACK.with_borrow_mut(|stack| {
+        if stack.entries.iter().any(|e| e.id == id) {
+            return;
+        }
+        if stack.entries.is_empty() {
+            if let Some(body) = document().and_then(|d| d.body()) {
+                let style = body.style();
+                stack.body_overflow = style.get_property_value("overflow").ok();
+                let _ = style.set_property("overflow", "hidden");
+            }
+        }
+        stack.entries.push(Entry {
+            id: id.to_string(),
+            restore_focus: active_element(),
+        });
+    });
+}
+
+/// Removes the dialog `id`, giving the focus back to the element focused when it opened.
+pub(super) fn remove(id: &str) {
+    let entry = STACK.with_borrow_mut(|stack| {
+        let index = stack.entries.iter().position(|e| e.id == id)?;
+        let entry = stack.entries.remove(index);
+        if stack.entries.is_empty() {
+            if let Some(body) = document().and_then(|d| d.body()) {
+                let overflow = stack.body_overflow.take().unwrap_or_default();
+                let _ = body.style().set_property("overflow", &overflow);
+            }
+        }
+        Some(entry)
+    });
+    if let Some(el) = entry.and_then(|e| e.restore_focus) {
+        let _ = el.focus();
+    }
+}
+
+/// Whether the dialog `id` is the top one.
+pub(super) fn is_top(id: &str) -> bool {
+    STACK.with_borrow(|stack| stack.entries.last().is_some_and(|e| e.id == id))
+}
+
+/// The elements reachable by Tab inside `container`, in document order.
+pub(super) fn focusable_elements(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(list) = container.query_selector_all(FOCUSABLE) else {
+        return vec![];
+    };
+    (0..list.length())
+        .filter_map(|i| list.get(i)?.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+/// Keeps Tab and Shift+Tab cycling inside `container`.
+pub(super) fn trap_focus(container: &web_sys::Element, event: &web_sys::KeyboardEvent) {
+    let elements = focusable_elements(container);
+    let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+        event.prevent_default();
+        return;
+    };
+    let active = active_element();
+    let outside = !container.contains(active.as_ref().map(|e| e.as_ref()));
+    if event.shift_key() {
+        if outside || active.as_ref() == Some(first) {
+            event.prevent_default();
+            let _ = last.focus();
+        }
+    } else if outside || active.as_ref() == Some(last) {
+        event.prevent_default();
+        let _ = first.focus();
+    }
+}
@@ -0,0 +1,313 @@
+//! Positioning of floating elements like tooltips and popovers next to a reference
+//! element, flipping to the other side and shifting along it to stay in the viewport.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::components::element_by_id;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Placement {
+    fn opposite(self) -> Self {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::Bottom => Placement::Top,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Placement::Top | Placement::Bottom)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatingOptions {
+    /// The preferred side of the reference, flipped if there is more room on the other.
+    pub placement: Placement,
+    /// The gap between the reference and the floating element.
+    pub offset: f64,
+    /// The minimum distance between the floating element and the viewport edges.
+    pub padding: f64,
+    /// The minimum distance between the arrow and the floating element corners.
+    pub arrow_padding: f64,
+}
+
+impl Default for FloatingOptions {
+    fn default() -> Self {
+        Self {
+            placement: Placement::Bottom,
+            offset: 8.0,
+            padding: 8.0,
+            arrow_padding: 12.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl From<web_sys::DomRect> for Rect {
+    fn from(r: web_sys::DomRect) -> Self {
+        Rect {
+            x: r.x(),
+            y: r.y(),
+            width: r.width(),
+            height: r.height(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatingPosition {
+    /// The viewport coordinates of the floating element.
+    pub x: f64,
+    pub y: f64,
+    /// The side actually used, after flipping.
+    pub placement: Placement,
+    /// The position of the arrow center along the side facing the reference.
+    pub arrow: f64,
+}
+
+/// Places a `floating` element of the given size next to `reference` inside `viewport`.
+pub fn compute_position(
+    reference: Rect,
+    floating: (f64, f64),
+    viewport: (f64, f64),
+    options: &FloatingOptions,
+) -> FloatingPosition {
+    let (width, height) = floating;
+    let room = |placement: Placement| match placement {
+        Placement::Top => reference.y,
+        Placement::Bottom => viewport.1 - reference.y - reference.height,
+        Placement::Left => reference.x,
+        Placement::Right => viewport.0 - reference.x - reference.width,
+    };
+    let needed = |placement: Placement| {
+        let size = if placement.is_vertical() {
+            height
+        } else {
+            width
+        };
+        size + options.offset + options.padding
+    };
+
+    let mut placement = options.placement;
+    if room(placement) < needed(placement) && room(placement.opposite()) > room(placement) {
+        placement = placement.opposite();
+    }
+
+    let (mut x, mut y) = match placement {
+        Placement::Top => (
+            reference.x + (reference.width - width) / 2.0,
+            reference.y - height - options.offset,
+        ),
+        Placement::Bottom => (
+            reference.x + (reference.width - width) / 2.0,
+            reference.y + reference.height + options.offset,
+        ),
+        Placement::Left => (
+            reference.x - width - options.offset,
+            reference.y + (reference.height - height) / 2.0,
+        ),
+        Placement::Right => (
+            reference.x + reference.width + options.offset,
+            reference.y + (reference.height - height) / 2.0,
+        ),
+    };
+
+    // Shift along the side, preferring the start edge when it doesn't fit at all
+    let shift = |start: f64, size: f64, limit: f64| {
+        start
+            .min(limit - size - options.padding)
+            .max(options.padding)
+    };
+    let arrow = if placement.is_vertical() {
+        x = shift(x, width, viewport.0);
+        reference.x + reference.width / 2.0 - x
+    } else {
+        y = shift(y, height, viewport.1);
+        reference.y + reference.height / 2.0 - y
+    };
+    let size = if placement.is_vertical() {
+        width
+    } else {
+        height
+    };
+    let arrow = arrow
+        .min(size - options.arrow_padding)
+        .max(options.arrow_padding.min(size / 2.0));
+
+    FloatingPosition {
+        x,
+        y,
+        placement,
+        arrow,
+    }
+}
+
+/// The state of [use_floating].
+#[derive(Clone)]
+pub struct Floating {
+    pub position: Signal<Option<FloatingPosition>>,
+    update: Rc<dyn Fn()>,
+}
+
+impl Floating {
+    /// Measures the elements again. Call it once the floating element is mounted.
+    pub fn update(&self) {
+        (self.update)()
+    }
+
+    /// The style of the floating element, hidden until it has been measured.
+    pub fn style(&self) -> String {
+        match *self.position.read() {
+            Some(p) => format!("position: fixed; left: {}px; top: {}px;", p.x, p.y),
+            None => "position: fixed; left: 0; top: 0; visibility: hidden;".to_string(),
+        }
+    }
+
+    /// The style of an absolutely positioned arrow inside the floating element,
+    /// centered on the side facing the reference.
+    pub fn arrow_style(&self, size: f64) -> String {
+        let Some(p) = *self.position.read() else {
+            return "display: none;".to_string();
+        };
+        let along = p.arrow - size / 2.0;
+        let across = -size / 2.0;
+        match p.placement {
+            Placement::Top => format!("left: {}px; bottom: {}px;", along, across),
+            Placement::Bottom => format!("left: {}px; top: {}px;", along, across),
+            Placement::Left => format!("top: {}px; right: {}px;", along, across),
+            Placement::Right => format!("top: {}px; left: {}px;", along, across),
+        }
+    }
+}
+
+/// Keeps the element `floating` next to the element `reference` while the page scrolls
+/// or resizes. The position is `None` while either element isn't in the document.
+pub fn use_floating(reference: String, floating: String, options: FloatingOptions) -> Floating {
+    let position = use_signal(|| None::<FloatingPosition>);
+
+    let update: Rc<dyn Fn()> = use_hook(|| {
+        Rc::new(move || {
+            let mut position = position;
+            let (Some(r), Some(f)) = (element_by_id(&reference), element_by_id(&floating)) else {
+                if position.peek().is_some() {
+                    *position.write() = None;
+                }
+                return;
+            };
+            let window = web_sys::window().unwrap();
+            let viewport = (
+                window
+                    .inner_width()
+                    .ok()
+                    .and_then(|w| w.as_f64())
+                    .unwrap_or(0.0),
+                window
+                    .inner_height()
+                    .ok()
+                    .and_then(|h| h.as_f64())
+                    .unwrap_or(0.0),
+            );
+            let rect = Rect::from(f.get_bounding_client_rect());
+            // A transformed ancestor, like an animated modal, moves fixed elements too
+            let (left, top) = position.peek().map_or((0.0, 0.0), |p| (p.x, p.y));
+            let origin = (rect.x - left, rect.y - top);
+
+            let mut p = compute_position(
+                r.get_bounding_client_rect().into(),
+                (rect.width, rect.height),
+                viewport,
+                &options,
+            );
+            p.x -= origin.0;
+            p.y -= origin.1;
+            if *position.peek() != Some(p) {
+                *position.write() = Some(p);
+            }
+        }) as Rc<dyn Fn()>
+    });
+
+    let listener = use_hook({
+        let update = update.clone();
+        move || {
+            let closure = Closure::<dyn FnMut()>::new(move || {
+                // Skip the measuring for the many closed instances of a page
+                if position.peek().is_some() {
+                    update();
+                }
+            });
+            let window = web_sys::window().unwrap();
+            let callback = closure.as_ref().unchecked_ref();
+            // Capture scrolls of any container, not just the page
+            let _ = window.add_event_listener_with_callback_and_bool("scroll", callback, true);
+            let _ = window.add_event_listener_with_callback("resize", callback);
+            Rc::new(closure)
+        }
+    });
+
+    use_drop(move || {
+        let window = web_sys::window().unwrap();
+        let callback = (*listener).as_ref().unchecked_ref();
+        let _ = window.remove_event_listener_with_callback_and_bool("scroll", callback, true);
+        let _ = window.remove_event_listener_with_callback("resize", callback);
+    });
+
+    Floating { position, update }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VIEWPORT: (f64, f64) = (400.0, 800.0);
+
+    fn rect(x: f64, y: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width: 40.0,
+            height: 20.0,
+        }
+    }
+
+    #[test]
+    fn test_compute_position() {
+        let options = FloatingOptions::default();
+
+        let p = compute_position(rect(180.0, 100.0), (200.0, 100.0), VIEWPORT, &options);
+        assert_eq!((p.x, p.y, p.placement), (100.0, 128.0, Placement::Bottom));
+        assert_eq!(p.arrow, 100.0);
+
+        // Flipped above at the bottom of the viewport
+        let p = compute_position(rect(180.0, 750.0), (200.0, 100.0), VIEWPORT, &options);
+        assert_eq!((p.y, p.placement), (642.0, Placement::Top));
+
+        // Shifted inside the viewport, with the arrow still pointing at the reference
+        let p = compute_position(rect(350.0, 100.0), (200.0, 100.0), VIEWPORT, &options);
+        assert_eq!(p.x, 192.0);
+        assert_eq!(p.arrow, 178.0);
+
+        let p = compute_position(rect(0.0, 100.0), (200.0, 100.0), VIEWPORT, &options);
+        assert_eq!(p.x, 8.0);
+        assert_eq!(p.arrow, 12.0);
+    }
+}
@@ -0,0 +1,405 @@
+use dioxus::dioxus_core::AttributeValue;
+use dioxus::prelude::*;
+use dioxus::web::WebEventExt;
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::components::{
+    count_by_role, focus, navigate, scroll_into_view, use_id, use_outside_click, RenderFn,
+};
+
+#[derive(Clone)]
+struct ListboxState {
+    id: String,
+    open: Signal<bool>,
+    active: Signal<Option<usize>>,
+    selected: Signal<usize>,
+    on_change: EventHandler<usize>,
+}
+
+impl ListboxState {
+    fn button_id(&self) -> String {
+        format!("{}-button", self.id)
+    }
+
+    fn options_id(&self) -> String {
+        format!("{}-options", self.id)
+    }
+
+    fn option_id(&self, index: usize) -> String {
+        format!("{}-option-{}", self.id, index)
+    }
+
+    fn set_open(&mut self, open: bool) {
+        *self.open.write() = open;
+        *self.active.write() = if open {
+            Some(*self.selected.peek())
+        } else {
+            None
+        };
+    }
+
+    fn close(&mut self) {
+        self.set_open(false);
+        focus(&self.button_id());
+    }
+
+    fn select(&mut self, index: usize) {
+        self.close();
+        self.on_change.call(index);
+    }
+}
+
+pub struct ListboxRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The main Listbox component, a select box. Options are identified by their index.
+#[component]
+pub fn Listbox(
+    /// The index of the selected option.
+    value: usize,
+    /// Called with the index of the option selected by click, Enter or Space.
+    on_change: EventHandler<usize>,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<ListboxRenderArgs>>,
+    children: Element,
+) -> Element {
+    let id = use_id("listbox");
+    let state = use_signal(|| ListboxState {
+        id,
+        open: Signal::new(false),
+        active: Signal::new(None),
+        selected: Signal::new(value),
+        on_change,
+    });
+    let mut state = use_context_provider(|| state());
+    if *state.selected.peek() != value {
+        *state.selected.write() = value;
+    }
+    use_outside_click(state.id.clone(), state.open);
+
+    let mut attrs = vec![Attribute::new(
+        "id",
+        AttributeValue::Text(state.id.clone()),
+        None,
+        false,
+    )];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(ListboxRenderArgs {
+            attrs,
+            children,
+            open: (state.open)(),
+        })
+    } else {
+        rsx! {
+            div {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct ListboxButtonRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The button which toggles the options. Arrow keys open them too.
+#[component]
+pub fn ListboxButton(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<ListboxButtonRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state =
+        try_use_context::<ListboxState>().expect("ListboxButton must be a child of Listbox");
+    let open = (state.open)();
+
+    let mut attrs = vec![
+        Attribute::new("id", AttributeValue::Text(state.button_id()), None, false),
+        Attribute::new(
+            "type",
+            AttributeValue::Text("button".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-haspopup",
+            AttributeValue::Text("listbox".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-controls",
+            AttributeValue::Text(state.options_id()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-expanded",
+            AttributeValue::Text(open.to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onclick",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |_: Event<PlatformEventData>| {
+                    let open = *state.open.peek();
+                    state.set_open(!open);
+                }
+            }),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onkeydown",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |event: Event<PlatformEventData>| {
+                    let e: KeyboardEvent = event.map(|e| e.into());
+                    let web_event = e.web_event();
+                    if matches!(web_event.key().as_str(), "ArrowDown" | "ArrowUp") {
+                        web_event.prevent_default();
+                        state.set_open(true);
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(ListboxButtonRenderArgs {
+            attrs,
+            children,
+            open,
+        })
+    } else {
+        rsx! {
+            button {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct ListboxOptionsRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The list of options, focused while open. Arrow keys, Home and End move the active
+/// option, Enter or Space selects it and Escape closes the list.
+#[component]
+pub fn ListboxOptions(
+    #[props(default = false)] r#static: bool,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<ListboxOptionsRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state =
+        try_use_context::<ListboxState>().expect("ListboxOptions must be a child of Listbox");
+    let open = (state.open)();
+    if !open && !r#static {
+        return None;
+    }
+
+    let mut attrs = vec![
+        Attribute::new("id", AttributeValue::Text(state.options_id()), None, false),
+        Attribute::new(
+            "role",
+            AttributeValue::Text("listbox".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-labelledby",
+            AttributeValue::Text(state.button_id()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "tabindex",
+            AttributeValue::Text("-1".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onmounted",
+            AttributeValue::listener({
+                let state = state.clone();
+                move |event: Event<PlatformEventData>| {
+                    let e: MountedEvent = event.map(|e| e.into());
+                    if let Some(el) = e.web_event().dyn_ref::<web_sys::HtmlElement>() {
+                        let _ = el.focus();
+                    }
+                    if let Some(active) = *state.active.peek() {
+                        scroll_into_view(&state.option_id(active));
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onkeydown",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |event: Event<PlatformEventData>| {
+                    let e: KeyboardEvent = event.map(|e| e.into());
+                    let web_event = e.web_event();
+                    let key = web_event.key();
+                    let count = count_by_role(&state.options_id(), "option");
+                    if let Some(active) = navigate(&key, *state.active.peek(), count) {
+                        web_event.prevent_default();
+                        *state.active.write() = active;
+                        if let Some(active) = active {
+                            scroll_into_view(&state.option_id(active));
+                        }
+                        return;
+                    }
+                    match key.as_str() {
+                        "Enter" | " " => {
+                            web_event.prevent_default();
+                            match *state.active.peek() {
+                                Some(active) if active < count => state.select(active),
+                                _ => state.close(),
+                            }
+                        }
+                        "Escape" => {
+                            web_event.prevent_default();
+                            state.close();
+                        }
+                        "Tab" => state.set_open(false),
+                        _ => {}
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    if let Some(active) = (state.active)() {
+        attrs.push(Attribute::new(
+            "aria-activedescendant",
+            AttributeValue::Text(state.option_id(active)),
+            None,
+            false,
+        ));
+    }
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(ListboxOptionsRenderArgs {
+            attrs,
+            children,
+            open,
+        })
+    } else {
+        rsx! {
+            ul {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct ListboxOptionRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub active: bool,
+    pub selected: bool,
+}
+
+/// An option. Indexes must follow the rendering order, starting from 0.
+#[component]
+pub fn ListboxOption(
+    index: usize,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<ListboxOptionRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state =
+        try_use_context::<ListboxState>().expect("ListboxOption must be a child of Listbox");
+    let active = (state.active)() == Some(index);
+    let selected = (state.selected)() == index;
+
+    let mut attrs = vec![
+        Attribute::new(
+            "id",
+            AttributeValue::Text(state.option_id(index)),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "role",
+            AttributeValue::Text("option".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-selected",
+            AttributeValue::Text(selected.to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onmousemove",
+            AttributeValue::listener({
+                let mut active_index = state.active;
+                move |_: Event<PlatformEventData>| {
+                    if *active_index.peek() != Some(index) {
+                        *active_index.write() = Some(index);
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onclick",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |_: Event<PlatformEventData>| {
+                    state.select(index);
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(ListboxOptionRenderArgs {
+            attrs,
+            children,
+            active,
+            selected,
+        })
+    } else {
+        rsx! {
+            li {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use dioxus::dioxus_core::AttributeValue;
+use dioxus::prelude::*;
+use dioxus::web::WebEventExt;
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::components::{
+    count_by_role, element_by_id, focus, navigate, use_id, use_outside_click, RenderFn,
+};
+
+#[derive(Clone)]
+struct MenuState {
+    id: String,
+    open: Signal<bool>,
+    active: Signal<Option<usize>>,
+}
+
+impl MenuState {
+    fn button_id(&self) -> String {
+        format!("{}-button", self.id)
+    }
+
+    fn items_id(&self) -> String {
+        format!("{}-items", self.id)
+    }
+
+    fn item_id(&self, index: usize) -> String {
+        format!("{}-item-{}", self.id, index)
+    }
+
+    fn set_open(&mut self, open: bool) {
+        *self.open.write() = open;
+        *self.active.write() = None;
+    }
+
+    fn close(&mut self) {
+        self.set_open(false);
+        focus(&self.button_id());
+    }
+
+    /// Clicks the link or button inside the item, or the item itself.
+    fn click(&self, index: usize) {
+        let el = element_by_id(&self.item_id(index)).and_then(|item| {
+            item.query_selector("a, button")
+                .ok()
+                .flatten()
+                .or(Some(item))
+        });
+        if let Some(el) = el.and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok()) {
+            el.click();
+        }
+    }
+}
+
+pub struct MenuRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The main Menu component, a dropdown of actions or links.
+#[component]
+pub fn Menu(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<MenuRenderArgs>>,
+    children: Element,
+) -> Element {
+    let id = use_id("menu");
+    let state = use_signal(|| MenuState {
+        id,
+        open: Signal::new(false),
+        active: Signal::new(None),
+    });
+    let state = use_context_provider(|| state());
+    use_outside_click(state.id.clone(), state.open);
+
+    let mut attrs = vec![Attribute::new(
+        "id",
+        AttributeValue::Text(state.id.clone()),
+        None,
+        false,
+    )];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(MenuRenderArgs {
+            attrs,
+            children,
+            open: (state.open)(),
+        })
+    } else {
+        rsx! {
+            div {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct MenuButtonRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The button which toggles the items. Arrow keys open them too.
+#[component]
+pub fn MenuButton(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<MenuButtonRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state = try_use_context::<MenuState>().expect("MenuButton must be a child of Menu");
+    let open = (state.open)();
+
+    let mut attrs = vec![
+        Attribute::new("id", AttributeValue::Text(state.button_id()), None, false),
+        Attribute::new(
+            "type",
+            AttributeValue::Text("button".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-haspopup",
+            AttributeValue::Text("menu".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-controls",
+            AttributeValue::Text(state.items_id()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-expanded",
+            AttributeValue::Text(open.to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onclick",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |_: Event<PlatformEventData>| {
+                    let open = *state.open.peek();
+                    state.set_open(!open);
+                }
+            }),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onkeydown",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |event: Event<PlatformEventData>| {
+                    let e: KeyboardEvent = event.map(|e| e.into());
+                    let web_event = e.web_event();
+                    if matches!(web_event.key().as_str(), "ArrowDown" | "ArrowUp") {
+                        web_event.prevent_default();
+                        state.set_open(true);
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(MenuButtonRenderArgs {
+            attrs,
+            children,
+            open,
+        })
+    } else {
+        rsx! {
+            button {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct MenuItemsRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The list of items, focused while open. Arrow keys, Home and End move the active
+/// item, Enter or Space activates it and Escape closes the menu.
+#[component]
+pub fn MenuItems(
+    #[props(default = false)] r#static: bool,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<MenuItemsRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state = try_use_context::<MenuState>().expect("MenuItems must be a child of Menu");
+    let open = (state.open)();
+    if !open && !r#static {
+        return None;
+    }
+
+    let mut attrs = vec![
+        Attribute::new("id", AttributeValue::Text(state.items_id()), None, false),
+        Attribute::new(
+            "role",
+            AttributeValue::Text("menu".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-labelledby",
+            AttributeValue::Text(state.button_id()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "tabindex",
+            AttributeValue::Text("-1".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onmounted",
+            AttributeValue::listener(move |event: Event<PlatformEventData>| {
+                let e: MountedEvent = event.map(|e| e.into());
+                if let Some(el) = e.web_event().dyn_ref::<web_sys::HtmlElement>() {
+                    let _ = el.focus();
+                }
+            }),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onkeydown",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |event: Event<PlatformEventData>| {
+                    let e: KeyboardEvent = event.map(|e| e.into());
+                    let web_event = e.web_event();
+                    let key = web_event.key();
+                    let count = count_by_role(&state.items_id(), "menuitem");
+                    if let Some(active) = navigate(&key, *state.active.peek(), count) {
+                        web_event.prevent_default();
+                        *state.active.write() = active;
+                        return;
+                    }
+                    match key.as_str() {
+                        "Enter" | " " => {
+                            web_event.prevent_default();
+                            match *state.active.peek() {
+                                Some(active) if active < count => state.click(active),
+                                _ => state.close(),
+                            }
+                        }
+                        "Escape" => {
+                            web_event.prevent_default();
+                            state.close();
+                        }
+                        "Tab" => state.set_open(false),
+                        _ => {}
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    if let Some(active) = (state.active)() {
+        attrs.push(Attribute::new(
+            "aria-activedescendant",
+            AttributeValue::Text(state.item_id(active)),
+            None,
+            false,
+        ));
+    }
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(MenuItemsRenderArgs {
+            attrs,
+            children,
+            open,
+        })
+    } else {
+        rsx! {
+            ul {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct MenuItemRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub active: bool,
+}
+
+/// An item, which closes the menu when clicked.
+/// Indexes must follow the rendering order, starting from 0.
+#[component]
+pub fn MenuItem(
+    index: usize,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<MenuItemRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state = try_use_context::<MenuState>().expect("MenuItem must be a child of Menu");
+    let active = (state.active)() == Some(index);
+
+    let mut attrs = vec![
+        Attribute::new(
+            "id",
+            AttributeValue::Text(state.item_id(index)),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "role",
+            AttributeValue::Text("menuitem".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "tabindex",
+            AttributeValue::Text("-1".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onmousemove",
+            AttributeValue::listener({
+                let mut active_index = state.active;
+                move |_: Event<PlatformEventData>| {
+                    if *active_index.peek() != Some(index) {
+                        *active_index.write() = Some(index);
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onclick",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |_: Event<PlatformEventData>| {
+                    state.close();
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(MenuItemRenderArgs {
+            attrs,
+            children,
+            active,
+        })
+    } else {
+        rsx! {
+            li {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
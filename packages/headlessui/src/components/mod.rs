@@ -1,9 +1,18 @@
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use dioxus::prelude::*;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{ScrollIntoViewOptions, ScrollLogicalPosition};
 
+pub mod combobox;
 pub mod dialog;
+pub mod floating;
+pub mod listbox;
+pub mod menu;
+pub mod popover;
 pub mod tabs;
 
 pub struct RenderFn<T = ()> {
@@ -42,3 +51,91 @@ impl<T> RenderFn<T> {
 }
 
 type RenderCallback<T> = Box<dyn FnMut(T) -> Element>;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Returns an id unique to the calling component, to relate elements by ARIA attributes.
+pub fn use_id(name: &str) -> String {
+    use_hook(|| {
+        format!(
+            "headlessui-{}-{}",
+            name,
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        )
+    })
+}
+
+pub(crate) fn element_by_id(id: &str) -> Option<web_sys::Element> {
+    web_sys::window()?.document()?.get_element_by_id(id)
+}
+
+/// Counts the elements with `role` inside the element `id`, since the number of
+/// items can't be known from the children.
+pub(crate) fn count_by_role(id: &str, role: &str) -> usize {
+    element_by_id(id)
+        .and_then(|e| e.query_selector_all(&format!("[role={}]", role)).ok())
+        .map_or(0, |list| list.length() as usize)
+}
+
+pub(crate) fn scroll_into_view(id: &str) {
+    if let Some(el) = element_by_id(id) {
+        let mut options = ScrollIntoViewOptions::new();
+        options.block(ScrollLogicalPosition::Nearest);
+        el.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+}
+
+pub(crate) fn focus(id: &str) {
+    if let Some(el) = element_by_id(id).and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok()) {
+        let _ = el.focus();
+    }
+}
+
+/// Moves the `active` index of `count` items for a navigation key, wrapping around.
+/// Returns `None` if `key` doesn't navigate.
+pub(crate) fn navigate(key: &str, active: Option<usize>, count: usize) -> Option<Option<usize>> {
+    if count == 0 {
+        return matches!(key, "ArrowDown" | "ArrowUp" | "Home" | "End").then_some(None);
+    }
+    let next = match (key, active) {
+        ("ArrowDown", Some(i)) => (i + 1) % count,
+        ("ArrowDown", None) | ("Home", _) => 0,
+        ("ArrowUp", Some(i)) => (i + count - 1) % count,
+        ("ArrowUp", None) | ("End", _) => count - 1,
+        _ => return None,
+    };
+    Some(Some(next))
+}
+
+/// Sets `open` to false when the user clicks outside of the element `id` while open.
+pub(crate) fn use_outside_click(id: String, mut open: Signal<bool>) {
+    let listener = use_hook(|| {
+        Rc::new(Closure::<dyn FnMut(web_sys::MouseEvent)>::new(
+            move |e: web_sys::MouseEvent| {
+                let target = e.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+                let inside = element_by_id(&id).is_some_and(|el| el.contains(target.as_ref()));
+                if !inside && *open.peek() {
+                    *open.write() = false;
+                }
+            },
+        ))
+    });
+
+    // Only listen while open, as there can be many closed instances on a page
+    let l = listener.clone();
+    use_effect(move || {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let callback = (*l).as_ref().unchecked_ref();
+        if open() {
+            let _ = document.add_event_listener_with_callback("mousedown", callback);
+        } else {
+            let _ = document.remove_event_listener_with_callback("mousedown", callback);
+        }
+    });
+
+    use_drop(move || {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let _ = document
+            .remove_event_listener_with_callback("mousedown", (*listener).as_ref().unchecked_ref());
+    });
+}
@@ -0,0 +1,287 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::dioxus_core::AttributeValue;
+use dioxus::prelude::*;
+use dioxus::web::WebEventExt;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::components::{focus, use_id, use_outside_click, RenderFn};
+
+/// How long the pointer may leave a hover Popover, to cross the gap to its panel.
+const HOVER_CLOSE_DELAY: i32 = 150;
+
+#[derive(Clone)]
+struct PopoverState {
+    id: String,
+    open: Signal<bool>,
+    /// Whether a mouse is over the Popover, when opening on hover.
+    hovering: Signal<bool>,
+}
+
+impl PopoverState {
+    fn button_id(&self) -> String {
+        format!("{}-button", self.id)
+    }
+
+    fn panel_id(&self) -> String {
+        format!("{}-panel", self.id)
+    }
+
+    fn close(&mut self) {
+        *self.open.write() = false;
+        focus(&self.button_id());
+    }
+}
+
+pub struct PopoverRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The main Popover component, a panel of arbitrary content shown next to a button.
+#[component]
+pub fn Popover(
+    /// Also opens while a mouse is over the Popover. Touch screens still open it by tapping.
+    #[props(default = false)]
+    hover: bool,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<PopoverRenderArgs>>,
+    children: Element,
+) -> Element {
+    let id = use_id("popover");
+    let state = use_signal(|| PopoverState {
+        id,
+        open: Signal::new(false),
+        hovering: Signal::new(false),
+    });
+    let state = use_context_provider(|| state());
+    use_outside_click(state.id.clone(), state.open);
+
+    // The pending close after the pointer left, cancelled on unmount as its signals
+    // are dropped with the Popover
+    let close_timeout = use_hook(|| Rc::new(Cell::new(None::<i32>)));
+    use_drop({
+        let close_timeout = close_timeout.clone();
+        move || {
+            if let Some(handle) = close_timeout.take() {
+                web_sys::window().unwrap().clear_timeout_with_handle(handle);
+            }
+        }
+    });
+
+    let mut attrs = vec![Attribute::new(
+        "id",
+        AttributeValue::Text(state.id.clone()),
+        None,
+        false,
+    )];
+    if hover {
+        attrs.push(Attribute::new(
+            "onpointerenter",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |event: Event<PlatformEventData>| {
+                    let e: PointerEvent = event.map(|e| e.into());
+                    if e.pointer_type() == "mouse" {
+                        *state.hovering.write() = true;
+                        *state.open.write() = true;
+                    }
+                }
+            }),
+            None,
+            false,
+        ));
+        attrs.push(Attribute::new(
+            "onpointerleave",
+            AttributeValue::listener({
+                let mut hovering = state.hovering;
+                let mut open = state.open;
+                move |event: Event<PlatformEventData>| {
+                    let e: PointerEvent = event.map(|e| e.into());
+                    if e.pointer_type() != "mouse" {
+                        return;
+                    }
+                    *hovering.write() = false;
+                    let window = web_sys::window().unwrap();
+                    if let Some(handle) = close_timeout.take() {
+                        window.clear_timeout_with_handle(handle);
+                    }
+                    let close = Closure::once_into_js({
+                        let close_timeout = close_timeout.clone();
+                        move || {
+                            close_timeout.set(None);
+                            if !*hovering.peek() {
+                                *open.write() = false;
+                            }
+                        }
+                    });
+                    let handle = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                        close.unchecked_ref(),
+                        HOVER_CLOSE_DELAY,
+                    );
+                    close_timeout.set(handle.ok());
+                }
+            }),
+            None,
+            false,
+        ));
+    }
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(PopoverRenderArgs {
+            attrs,
+            children,
+            open: (state.open)(),
+        })
+    } else {
+        rsx! {
+            div {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct PopoverButtonRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The button which toggles the panel, or only opens it while hovered.
+#[component]
+pub fn PopoverButton(
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<PopoverButtonRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state =
+        try_use_context::<PopoverState>().expect("PopoverButton must be a child of Popover");
+    let open = (state.open)();
+
+    let mut attrs = vec![
+        Attribute::new("id", AttributeValue::Text(state.button_id()), None, false),
+        Attribute::new(
+            "type",
+            AttributeValue::Text("button".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-controls",
+            AttributeValue::Text(state.panel_id()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "aria-expanded",
+            AttributeValue::Text(open.to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onclick",
+            AttributeValue::listener({
+                let mut open = state.open;
+                let hovering = state.hovering;
+                move |_: Event<PlatformEventData>| {
+                    // The panel is already open from the hover, so keep it for the click
+                    let value = *open.peek() && !*hovering.peek();
+                    *open.write() = !value;
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(PopoverButtonRenderArgs {
+            attrs,
+            children,
+            open,
+        })
+    } else {
+        rsx! {
+            button {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
+
+pub struct PopoverPanelRenderArgs {
+    pub attrs: Vec<Attribute>,
+    pub children: Element,
+    pub open: bool,
+}
+
+/// The panel, rendered while the Popover is open or always when `static`.
+/// Escape closes it and gives the focus back to the button.
+#[component]
+pub fn PopoverPanel(
+    #[props(default = false)] r#static: bool,
+    #[props(extends = GlobalAttributes)] attributes: Vec<Attribute>,
+    render: Option<RenderFn<PopoverPanelRenderArgs>>,
+    children: Element,
+) -> Element {
+    let state = try_use_context::<PopoverState>().expect("PopoverPanel must be a child of Popover");
+    let open = (state.open)();
+    if !open && !r#static {
+        return None;
+    }
+
+    let mut attrs = vec![
+        Attribute::new("id", AttributeValue::Text(state.panel_id()), None, false),
+        Attribute::new(
+            "tabindex",
+            AttributeValue::Text("-1".to_string()),
+            None,
+            false,
+        ),
+        Attribute::new(
+            "onkeydown",
+            AttributeValue::listener({
+                let mut state = state.clone();
+                move |event: Event<PlatformEventData>| {
+                    let e: KeyboardEvent = event.map(|e| e.into());
+                    let web_event = e.web_event();
+                    if web_event.key() == "Escape" {
+                        web_event.prevent_default();
+                        // Only close the innermost of nested popovers
+                        web_event.stop_propagation();
+                        state.close();
+                    }
+                }
+            }),
+            None,
+            false,
+        ),
+    ];
+    attrs.extend(attributes);
+    attrs.sort_by_key(|a| a.name);
+
+    if let Some(render) = render {
+        render.call(PopoverPanelRenderArgs {
+            attrs,
+            children,
+            open,
+        })
+    } else {
+        rsx! {
+            div {
+                ..attrs,
+                {children}
+            }
+        }
+    }
+}
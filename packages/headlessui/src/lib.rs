@@ -1,5 +1,10 @@
+pub use components::combobox::*;
 pub use components::dialog::*;
+pub use components::floating::*;
+pub use components::listbox::*;
+pub use components::menu::*;
+pub use components::popover::*;
 pub use components::tabs::*;
-pub use components::RenderFn;
+pub use components::{use_id, RenderFn};
 
 mod components;
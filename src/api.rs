@@ -0,0 +1,130 @@
+use std::fmt::{Display, Formatter};
+
+use auth0_spa::{use_auth0_context, UseAuth0};
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use build_format::api::{
+    BuildValue, GetViewerBuildResponse, ListViewerBuildResponse, PostViewerBuildRequest,
+    PostViewerBuildResponse, PutViewerBuildRequest, PutViewerBuildResponse,
+};
+use build_format::BuildMetadata;
+
+const API_URL: &str = "https://bb2b-api.atty303.workers.dev";
+
+#[derive(Debug)]
+pub enum ApiError {
+    /// Not logged in, or the token was rejected.
+    Unauthorized,
+    NotFound,
+    /// The build was updated by someone else since it was loaded.
+    Conflict,
+    Status(StatusCode, String),
+    Request(reqwest::Error),
+    Json(serde_json::Error),
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::Unauthorized => write!(f, "You need to log in"),
+            ApiError::NotFound => write!(f, "Build not found"),
+            ApiError::Conflict => write!(f, "The build was updated elsewhere"),
+            ApiError::Status(status, body) => write!(f, "{}: {}", status, body),
+            ApiError::Request(err) => write!(f, "{}", err),
+            ApiError::Json(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Client for the build API, authorized with the Auth0 access token.
+#[derive(Clone, Copy)]
+pub struct ApiClient {
+    auth: UseAuth0<String>,
+}
+
+pub fn use_api_client() -> ApiClient {
+    ApiClient {
+        auth: use_auth0_context(),
+    }
+}
+
+impl ApiClient {
+    pub fn is_authenticated(&self) -> bool {
+        self.auth.access_token().is_some()
+    }
+
+    pub async fn list_builds(&self) -> Result<Vec<(String, BuildMetadata)>, ApiError> {
+        let res: ListViewerBuildResponse = self
+            .request(Method::GET, "/viewer/build", None::<&()>)
+            .await?;
+        Ok(res.items)
+    }
+
+    pub async fn get_build(&self, id: &str) -> Result<GetViewerBuildResponse, ApiError> {
+        self.request(Method::GET, &format!("/viewer/build/{}", id), None::<&()>)
+            .await
+    }
+
+    /// Creates a build and returns its id.
+    pub async fn create_build(
+        &self,
+        body: String,
+        metadata: BuildMetadata,
+    ) -> Result<String, ApiError> {
+        let req = PostViewerBuildRequest {
+            value: BuildValue {
+                user_id: String::new(),
+                body,
+            },
+            metadata,
+        };
+        let res: PostViewerBuildResponse = self
+            .request(Method::POST, "/viewer/build", Some(&req))
+            .await?;
+        Ok(res.id)
+    }
+
+    /// Updates a build and returns the stored metadata.
+    pub async fn update_build(
+        &self,
+        id: &str,
+        req: &PutViewerBuildRequest,
+    ) -> Result<BuildMetadata, ApiError> {
+        let res: PutViewerBuildResponse = self
+            .request(Method::PUT, &format!("/viewer/build/{}", id), Some(req))
+            .await?;
+        Ok(res.metadata)
+    }
+
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&impl Serialize>,
+    ) -> Result<T, ApiError> {
+        let token = self.auth.access_token().ok_or(ApiError::Unauthorized)?;
+        let mut req = reqwest::Client::new()
+            .request(method, format!("{}{}", API_URL, path))
+            .bearer_auth(token);
+        if let Some(body) = body {
+            req = req
+                .header("content-type", "application/json")
+                .body(serde_json::to_string(body).map_err(ApiError::Json)?);
+        }
+
+        let res = req.send().await.map_err(ApiError::Request)?;
+        let status = res.status();
+        let text = res.text().await.map_err(ApiError::Request)?;
+        match status {
+            StatusCode::UNAUTHORIZED => Err(ApiError::Unauthorized),
+            StatusCode::NOT_FOUND => Err(ApiError::NotFound),
+            StatusCode::CONFLICT => Err(ApiError::Conflict),
+            s if s.is_success() => serde_json::from_str(&text).map_err(ApiError::Json),
+            s => Err(ApiError::Status(s, text)),
+        }
+    }
+}
@@ -1,5 +1,7 @@
+pub use self::palette::*;
 pub use self::rune::*;
 pub use self::skill::*;
 
+mod palette;
 mod rune;
 mod skill;
@@ -0,0 +1,258 @@
+use std::rc::Rc;
+
+use classes::classes;
+use dioxus::prelude::*;
+use dioxus::router::router;
+use headlessui::{
+    Combobox, ComboboxInput, ComboboxOption, ComboboxOptionRenderArgs, ComboboxOptions, RenderFn,
+};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+use crate::global::{DATABASE, SEARCH_CATALOGS};
+use crate::pages::skill::SkillListState;
+use crate::pages::{GlossaryState, Route};
+use crate::search::GlobalSearchItem;
+use crate::ui::{Icon, SpriteIcon};
+use crate::Language;
+
+/// Results shown for each kind of item.
+const MAX_RESULTS: usize = 5;
+
+const STATE_ICON: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" class="w-6 h-6"><path stroke-linecap="round" stroke-linejoin="round" d="M9.813 15.904 9 18.75l-.813-2.846a4.5 4.5 0 0 0-3.09-3.09L2.25 12l2.846-.813a4.5 4.5 0 0 0 3.09-3.09L9 5.25l.813 2.846a4.5 4.5 0 0 0 3.09 3.09L15.75 12l-2.846.813a4.5 4.5 0 0 0-3.09 3.09Z" /></svg>"#;
+const ENEMY_ICON: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" class="w-6 h-6"><path stroke-linecap="round" stroke-linejoin="round" d="M12 12.75c1.148 0 2.278.08 3.383.237 1.037.146 1.866.966 1.866 2.013 0 3.728-2.35 6.75-5.25 6.75S6.75 18.728 6.75 15c0-1.046.83-1.867 1.866-2.013A24.204 24.204 0 0 1 12 12.75Zm0 0c2.883 0 5.647.508 8.207 1.44a23.91 23.91 0 0 1-1.152 6.06M12 12.75c-2.883 0-5.647.508-8.208 1.44.125 2.104.52 4.136 1.153 6.06M12 12.75a2.25 2.25 0 0 0 2.248-2.354M12 12.75a2.25 2.25 0 0 1-2.248-2.354M12 8.25c.995 0 1.971-.08 2.922-.236.403-.066.74-.358.795-.762a3.778 3.778 0 0 0-.399-2.25M12 8.25c-.995 0-1.97-.08-2.922-.236-.402-.066-.74-.358-.795-.762a3.734 3.734 0 0 1 .4-2.253M12 8.25a2.25 2.25 0 0 0-2.248 2.146M12 8.25a2.25 2.25 0 0 1 2.248 2.146M8.683 5a6.032 6.032 0 0 1-1.155-1.002c.07-.63.27-1.222.574-1.747m.581 2.749A3.75 3.75 0 0 1 15.318 5m0 0c.427-.283.815-.62 1.155-.999a4.471 4.471 0 0 0-.575-1.752M4.921 6a24.048 24.048 0 0 0-.392 3.314c1.668.546 3.416.914 5.223 1.082M19.08 6c.205 1.08.337 2.187.392 3.314a23.882 23.882 0 0 1-5.223 1.082" /></svg>"#;
+const TERM_ICON: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" class="w-6 h-6"><path stroke-linecap="round" stroke-linejoin="round" d="M12 6.042A8.967 8.967 0 0 0 6 3.75c-1.052 0-2.062.18-3 .512v14.25A8.987 8.987 0 0 1 6 18c2.305 0 4.408.867 6 2.292m0-14.25a8.966 8.966 0 0 1 6-2.292c1.052 0 2.062.18 3 .512v14.25A8.987 8.987 0 0 0 18 18a8.967 8.967 0 0 0-6 2.292m0-14.25v14.25" /></svg>"#;
+
+/// An item of the palette, ready to render.
+struct Entry {
+    group: &'static str,
+    name: String,
+    icon: EntryIcon,
+    route: Route,
+}
+
+enum EntryIcon {
+    Sprite(data::Sprite),
+    Svg(&'static str),
+}
+
+impl Entry {
+    fn new(item: &GlobalSearchItem, language: &Language) -> Option<Self> {
+        let database = DATABASE();
+        let term = |id: &str| database.term.get_str(&format!("NM-{}", id));
+        // States, enemies and terms have no page of their own, and the glossary only
+        // lists the keywords explained by tooltips. Others list the skills mentioning them.
+        let detail = |id: &str| {
+            if database.in_glossary(id) {
+                Route::GlossaryPage {
+                    language: language.clone(),
+                    state: GlossaryState::term(id),
+                }
+            } else {
+                Route::SkillListPage {
+                    language: language.clone(),
+                    state: SkillListState::new(format!("\"{}\"", term(id))),
+                }
+            }
+        };
+        let entry = match item {
+            GlobalSearchItem::Skill(hash) => {
+                let skill = database.skill.get(hash)?;
+                Entry {
+                    group: "Skills",
+                    name: skill.name.clone(),
+                    icon: EntryIcon::Sprite(skill.modes[0].icon.clone()),
+                    route: Route::SkillPage {
+                        language: language.clone(),
+                        skill_id: skill.id.clone(),
+                    },
+                }
+            }
+            GlobalSearchItem::Rune(hash) => {
+                let rune = database.rune.get(hash)?;
+                Entry {
+                    group: "Runes",
+                    name: rune.name.clone(),
+                    icon: EntryIcon::Sprite(rune.icon.clone()),
+                    route: Route::RunePage {
+                        language: language.clone(),
+                        rune_id: rune.id.clone(),
+                    },
+                }
+            }
+            GlobalSearchItem::State(id) => Entry {
+                group: "States",
+                name: term(id),
+                icon: EntryIcon::Svg(STATE_ICON),
+                route: detail(id),
+            },
+            GlobalSearchItem::Enemy(id) => Entry {
+                group: "Enemies",
+                name: term(id),
+                icon: EntryIcon::Svg(ENEMY_ICON),
+                route: detail(id),
+            },
+            GlobalSearchItem::Term(id) => Entry {
+                group: "Terms",
+                name: term(id),
+                icon: EntryIcon::Svg(TERM_ICON),
+                route: detail(id),
+            },
+        };
+        Some(entry)
+    }
+}
+
+/// Opens the palette on Ctrl+K, Cmd+K, or `/` outside of text fields.
+fn use_palette_shortcut(mut open: Signal<bool>) {
+    let listener = use_hook(|| {
+        let closure =
+            Closure::<dyn FnMut(web_sys::KeyboardEvent)>::new(move |e: web_sys::KeyboardEvent| {
+                let editing = e
+                    .target()
+                    .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+                    .is_some_and(|el| {
+                        matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                            || el.is_content_editable()
+                    });
+                let shortcut = (e.ctrl_key() || e.meta_key()) && e.key() == "k";
+                if shortcut || (e.key() == "/" && !editing) {
+                    e.prevent_default();
+                    *open.write() = true;
+                }
+            });
+        web_sys::window()
+            .unwrap()
+            .document()
+            .unwrap()
+            .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref())
+            .unwrap();
+        Rc::new(closure)
+    });
+
+    use_drop(move || {
+        web_sys::window()
+            .unwrap()
+            .document()
+            .unwrap()
+            .remove_event_listener_with_callback("keydown", (*listener).as_ref().unchecked_ref())
+            .unwrap();
+    });
+}
+
+/// A search box in the navbar which finds skills, runes, states, enemies and terms at once.
+/// Databases generated before states and enemies were told apart list them all as terms.
+#[component]
+pub fn CommandPalette(language: Language) -> Element {
+    let mut open = use_signal(|| false);
+    let mut query = use_signal(String::new);
+    let results = use_memo(move || {
+        if query().trim().is_empty() {
+            vec![]
+        } else {
+            SEARCH_CATALOGS.read().search_all(&query(), MAX_RESULTS)
+        }
+    });
+    use_palette_shortcut(open);
+
+    let placeholder = if DATABASE().global.state_ids.is_empty() {
+        "Search skills, runes and terms..."
+    } else {
+        "Search skills, runes, states, enemies and terms..."
+    };
+    let entries = results()
+        .iter()
+        .filter_map(|item| Entry::new(item, &language))
+        .collect::<Vec<_>>();
+
+    let mut close = move || {
+        *open.write() = false;
+        *query.write() = String::new();
+    };
+
+    let render_option = RenderFn::<ComboboxOptionRenderArgs>::new(move |args| {
+        let class = classes![
+            "flex flex-row items-center gap-2 rounded-btn px-2 py-1 cursor-pointer",
+            "bg-primary text-primary-content" => args.active
+        ];
+        rsx! {
+            li {
+                class,
+                ..args.attrs,
+                {args.children}
+            }
+        }
+    });
+
+    rsx! {
+        button {
+            class: "btn btn-ghost btn-sm rounded-btn",
+            onclick: move |_| *open.write() = true,
+            Icon { svg: r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" class="w-6 h-6"><path stroke-linecap="round" stroke-linejoin="round" d="m21 21-5.197-5.197m0 0A7.5 7.5 0 1 0 5.196 5.196a7.5 7.5 0 0 0 10.607 10.607Z" /></svg>"# }
+            span { class: "hidden md:inline", "Search" }
+            kbd { class: "kbd kbd-sm hidden md:inline text-base-content", "Ctrl K" }
+        }
+
+        if open() {
+            div {
+                class: "modal modal-open modal-top backdrop-blur",
+                onclick: move |_| close(),
+                onkeydown: move |e| {
+                    if e.key() == Key::Escape {
+                        close();
+                    }
+                },
+                div {
+                    class: "modal-box max-w-2xl mx-auto mt-16 p-2 rounded-box",
+                    onclick: move |e| e.stop_propagation(),
+                    Combobox {
+                        on_change: move |index: usize| {
+                            let entry = results.read().get(index).and_then(|item| Entry::new(item, &language));
+                            if let Some(entry) = entry {
+                                router().push(entry.route);
+                            }
+                            close();
+                        },
+                        ComboboxInput {
+                            class: "input input-bordered input-primary w-full",
+                            placeholder: "{placeholder}",
+                            auto_focus: true,
+                            value: "{query}",
+                            on_change: move |q: String| *query.write() = q
+                        }
+                        ComboboxOptions {
+                            class: "menu menu-sm max-h-96 overflow-y-auto flex-nowrap p-0 mt-2",
+                            r#static: true,
+                            for (index, entry) in entries.iter().enumerate() {
+                                if index == 0 || entries[index - 1].group != entry.group {
+                                    li { class: "menu-title", "{entry.group}" }
+                                }
+                                ComboboxOption {
+                                    key: "{index}",
+                                    index,
+                                    render: Some(render_option.clone()),
+                                    match &entry.icon {
+                                        EntryIcon::Sprite(sprite) => rsx! {
+                                            SpriteIcon {
+                                                class: "rounded-md",
+                                                sprite: Signal::new(sprite.clone()),
+                                                size: 24
+                                            }
+                                        },
+                                        EntryIcon::Svg(svg) => rsx! {
+                                            Icon { class: "w-6 h-6", svg: *svg }
+                                        },
+                                    }
+                                    span { class: "flex-grow", "{entry.name}" }
+                                }
+                            }
+                            if !query().trim().is_empty() && entries.is_empty() {
+                                li { class: "p-2 text-sm opacity-70", "No results" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -3,13 +3,16 @@ use dioxus::prelude::*;
 use dioxus_router::prelude::Link;
 
 use crate::pages::Route;
-use crate::ui::{Description, Rarity, SpriteIcon};
+use crate::ui::{Description, Highlight, Rarity, SpriteIcon};
 
 #[component]
 pub fn RuneView(
     language: Language,
     rune: Signal<data::Rune>,
     #[props(default = false)] debug: bool,
+    /// Search terms to highlight in the name and description.
+    #[props(default)]
+    highlight: Vec<String>,
 ) -> Element {
     rsx! {
         div { class: "flex flex-col border-solid border border-base-300 rounded-md my-2",
@@ -22,12 +25,12 @@ pub fn RuneView(
                             language,
                             rune_id: rune().id,
                         },
-                        "{rune().name}"
+                        Highlight { text: rune().name, terms: highlight.clone() }
                     }
                 }
                 span { Rarity { rarity: rune().rarity } }
             }
-            div { class: "flex flex-row flex-wrap gap-2 p-2", Description { tokens: rune().format(), debug } }
+            div { class: "flex flex-row flex-wrap gap-2 p-2", Description { tokens: rune().format(), debug, highlight } }
         }
     }
 }
@@ -3,13 +3,16 @@ use crate::Language;
 use dioxus::prelude::*;
 use dioxus::router::prelude::Link;
 
-use crate::ui::{Description, Rarity, SpriteIcon};
+use crate::ui::{Description, Highlight, Rarity, SpriteIcon};
 
 #[component]
 pub fn SkillView(
     language: Language,
     skill: Signal<data::Skill>,
     #[props(default = false)] debug: bool,
+    /// Search terms to highlight in names and descriptions.
+    #[props(default)]
+    highlight: Vec<String>,
 ) -> Element {
     rsx! {
         div { class: "flex flex-col border-solid border border-base-300 rounded-md my-2",
@@ -26,14 +29,14 @@ pub fn SkillView(
                             language,
                             skill_id: skill().id.clone(),
                         },
-                        "{skill().name}"
+                        Highlight { text: skill().name, terms: highlight.clone() }
                     }
                 }
                 span { Rarity { rarity: skill().rarity } }
             }
             div { class: "flex flex-row flex-wrap gap-2 p-2",
                 for mode in skill().modes.iter() {
-                    div { class: "flex-1 min-w-64", SkillMode { mode: Signal::new(mode.clone()), debug } }
+                    div { class: "flex-1 min-w-64", SkillMode { mode: Signal::new(mode.clone()), debug, highlight: highlight.clone() } }
                 }
             }
         }
@@ -41,14 +44,20 @@ pub fn SkillView(
 }
 
 #[component]
-pub fn SkillMode(mode: Signal<data::SkillMode>, #[props(default = false)] debug: bool) -> Element {
+pub fn SkillMode(
+    mode: Signal<data::SkillMode>,
+    #[props(default = false)] debug: bool,
+    #[props(default)] highlight: Vec<String>,
+) -> Element {
     rsx! {
         div { class: "flex flex-col gap-2 bg-base-200 text-base-content rounded-md p-2",
             div { class: "flex flex-row items-center gap-2",
                 SpriteIcon { class: "rounded-md", sprite: Signal::new(mode().icon), size: 32 }
-                div { class: "flex-grow", "{mode().name}" }
+                div { class: "flex-grow",
+                    Highlight { text: mode().name, terms: highlight.clone() }
+                }
             }
-            div { class: "bg-base-100 p-2", Description { tokens: mode().format(), debug } }
+            div { class: "bg-base-100 p-2", Description { tokens: mode().format(), debug, highlight } }
         }
     }
 }
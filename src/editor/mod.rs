@@ -0,0 +1,37 @@
+use wasm_bindgen::prelude::*;
+
+pub use self::provider::*;
+
+mod provider;
+
+#[wasm_bindgen(module = "/src/bundle.js")]
+extern "C" {
+    pub type CodeMirror;
+
+    #[wasm_bindgen(constructor)]
+    pub fn new(parent: &web_sys::Element, on_change: &Closure<dyn FnMut(String)>) -> CodeMirror;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn value(this: &CodeMirror) -> String;
+
+    #[wasm_bindgen(method, setter)]
+    pub fn set_value(this: &CodeMirror, value: String);
+
+    /// `source(kind, query)` returns completion options for a reference being typed.
+    #[wasm_bindgen(method, js_name = setCompletionSource)]
+    pub fn set_completion_source(
+        this: &CodeMirror,
+        source: &Closure<dyn FnMut(String, String) -> JsValue>,
+    );
+
+    /// `source(line, offset)` returns the tooltip for the reference at `offset`, or `null`.
+    #[wasm_bindgen(method, js_name = setHoverSource)]
+    pub fn set_hover_source(
+        this: &CodeMirror,
+        source: &Closure<dyn FnMut(String, usize) -> JsValue>,
+    );
+
+    /// `source(doc)` returns diagnostics for unresolved references.
+    #[wasm_bindgen(method, js_name = setLintSource)]
+    pub fn set_lint_source(this: &CodeMirror, source: &Closure<dyn FnMut(String) -> JsValue>);
+}
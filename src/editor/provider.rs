@@ -0,0 +1,189 @@
+use dioxus::prelude::*;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use build_format::{reference_spans, Reference, ReferenceKind, TermNames};
+use data::{Database, Repository, Sprite};
+
+use crate::editor::CodeMirror;
+use crate::global::{DATABASE, SEARCH_CATALOGS, TERM_NAMES};
+use crate::search::SearchCatalogs;
+
+const MAX_COMPLETIONS: usize = 50;
+
+/// A completion option for `<skill:` and `<rune:`.
+#[derive(Serialize)]
+struct Completion {
+    label: String,
+    detail: String,
+    /// Text which replaces the typed reference.
+    apply: String,
+    sprite: Sprite,
+}
+
+/// Tooltip content for the reference under the pointer.
+/// `from` and `to` are UTF-16 offsets in the line.
+#[derive(Serialize)]
+struct Hover {
+    from: usize,
+    to: usize,
+    title: String,
+    description: String,
+    sprite: Option<Sprite>,
+}
+
+/// Lint marker for an unresolved reference.
+/// `from` and `to` are UTF-16 offsets in the document.
+#[derive(Serialize)]
+struct Diagnostic {
+    from: usize,
+    to: usize,
+    message: String,
+}
+
+/// Installs completion, hover and lint providers backed by the loaded database.
+/// The closures live as long as the page, like the change callback.
+pub fn install_providers(cm: &CodeMirror) {
+    // Global signals need the runtime, which is not current in callbacks from JS.
+    let database = DATABASE.signal();
+    let catalogs = SEARCH_CATALOGS.signal();
+    let names = TERM_NAMES.signal();
+
+    let complete_source = Closure::wrap(Box::new(move |kind: String, query: String| {
+        to_js(&complete(&database.peek(), &catalogs.peek(), &kind, &query))
+    }) as Box<dyn FnMut(String, String) -> JsValue>);
+    cm.set_completion_source(&complete_source);
+    complete_source.forget();
+
+    let hover_source = Closure::wrap(Box::new(move |line: String, offset: usize| {
+        to_js(&hover(&database.peek(), &names.peek(), &line, offset))
+    }) as Box<dyn FnMut(String, usize) -> JsValue>);
+    cm.set_hover_source(&hover_source);
+    hover_source.forget();
+
+    let lint_source = Closure::wrap(Box::new(move |doc: String| {
+        to_js(&lint(&database.peek(), &names.peek(), &doc))
+    }) as Box<dyn FnMut(String) -> JsValue>);
+    cm.set_lint_source(&lint_source);
+    lint_source.forget();
+}
+
+fn to_js<T: Serialize>(value: &T) -> JsValue {
+    serde_wasm_bindgen::to_value(value).unwrap_or(JsValue::NULL)
+}
+
+fn complete(
+    database: &Database,
+    catalogs: &SearchCatalogs,
+    kind: &str,
+    query: &str,
+) -> Vec<Completion> {
+    match kind {
+        "skill" => {
+            let skills: Vec<_> = if query.is_empty() {
+                database.skill.iter().collect()
+            } else {
+                catalogs
+                    .skill
+                    .search(query)
+                    .into_iter()
+                    .filter_map(|hash| database.skill.get(hash))
+                    .collect()
+            };
+            skills
+                .into_iter()
+                .take(MAX_COMPLETIONS)
+                .map(|skill| Completion {
+                    label: skill.name.clone(),
+                    detail: skill.id.clone(),
+                    apply: reference(ReferenceKind::Skill, &skill.id),
+                    sprite: skill.modes[0].icon.clone(),
+                })
+                .collect()
+        }
+        "rune" => {
+            let runes: Vec<_> = if query.is_empty() {
+                database.rune.iter().collect()
+            } else {
+                catalogs
+                    .rune
+                    .search(query)
+                    .into_iter()
+                    .filter_map(|hash| database.rune.get(hash))
+                    .collect()
+            };
+            runes
+                .into_iter()
+                .take(MAX_COMPLETIONS)
+                .map(|rune| Completion {
+                    label: rune.name.clone(),
+                    detail: rune.id.clone(),
+                    apply: reference(ReferenceKind::Rune, &rune.id),
+                    sprite: rune.icon.clone(),
+                })
+                .collect()
+        }
+        _ => vec![],
+    }
+}
+
+fn reference(kind: ReferenceKind, key: &str) -> String {
+    Reference {
+        kind,
+        key: key.to_string(),
+    }
+    .to_string()
+}
+
+/// `offset` is a UTF-16 offset in `line`, as CodeMirror counts positions.
+fn hover(database: &Database, names: &TermNames, line: &str, offset: usize) -> Option<Hover> {
+    let (range, r) = reference_spans(line).into_iter().find(|(range, _)| {
+        utf16_len(&line[..range.start]) <= offset && offset <= utf16_len(&line[..range.end])
+    })?;
+    let from = utf16_len(&line[..range.start]);
+    let to = utf16_len(&line[..range.end]);
+
+    match r.kind {
+        ReferenceKind::Skill => r.resolve_skill(database).map(|skill| Hover {
+            from,
+            to,
+            title: skill.name.clone(),
+            description: format!("{}", skill.modes[0].format()),
+            sprite: Some(skill.modes[0].icon.clone()),
+        }),
+        ReferenceKind::Rune => r.resolve_rune(database).map(|rune| Hover {
+            from,
+            to,
+            title: rune.name.clone(),
+            description: format!("{}", rune.format()),
+            sprite: Some(rune.icon.clone()),
+        }),
+        ReferenceKind::State | ReferenceKind::Enemy => r.resolve_term(names).map(|id| Hover {
+            from,
+            to,
+            title: database.term.get_str(&format!("NM-{}", id)),
+            description: database
+                .term
+                .try_get(&format!("DC-{}", id))
+                .map(|tokens| format!("{}", tokens))
+                .unwrap_or_default(),
+            sprite: None,
+        }),
+    }
+}
+
+fn lint(database: &Database, names: &TermNames, doc: &str) -> Vec<Diagnostic> {
+    reference_spans(doc)
+        .into_iter()
+        .filter(|(_, r)| !r.is_resolved(database, names))
+        .map(|(range, r)| Diagnostic {
+            from: utf16_len(&doc[..range.start]),
+            to: utf16_len(&doc[..range.end]),
+            message: format!("Unknown {}: {}", r.kind.prefix(), r.key),
+        })
+        .collect()
+}
+
+fn utf16_len(s: &str) -> usize {
+    s.encode_utf16().count()
+}
@@ -1,9 +1,22 @@
 use crate::search::SearchCatalogs;
-use data::Database;
+use build_format::TermNames;
+use data::{Database, DatabaseIndex};
 use dioxus::prelude::*;
+use std::rc::Rc;
 use std::string::ToString;
 
 pub static THEME: GlobalSignal<String> = Signal::global(|| "dark".to_string());
 pub static DATABASE: GlobalSignal<Database> = Signal::global(|| Database::default());
+/// The index of `DATABASE`, loaded before its chunks.
+pub static DATABASE_INDEX: GlobalSignal<DatabaseIndex> =
+    Signal::global(|| DatabaseIndex::default());
+/// Whether chunks are still being added to `DATABASE`.
+pub static DATABASE_LOADING: GlobalSignal<bool> = Signal::global(|| false);
+/// How much of the database of the current language has been downloaded, from 0 to 1.
+pub static DATABASE_PROGRESS: GlobalSignal<f64> = Signal::global(|| 0.0);
+/// Why the database of the current language couldn't be loaded.
+pub static DATABASE_ERROR: GlobalSignal<Option<Rc<anyhow::Error>>> = Signal::global(|| None);
+/// The state and enemy names of `DATABASE`, rebuilt when its terms change.
+pub static TERM_NAMES: GlobalSignal<TermNames> = Signal::global(|| TermNames::default());
 pub static SEARCH_CATALOGS: GlobalSignal<SearchCatalogs> =
     Signal::global(|| SearchCatalogs::default());
@@ -5,10 +5,12 @@ use dioxus::prelude::{current_scope_id, use_signal, Writable};
 pub use self::persistent::*;
 pub use self::search_rune::*;
 pub use self::search_skill::*;
+pub use self::service_worker::*;
 
 mod persistent;
 mod search_rune;
 mod search_skill;
+mod service_worker;
 
 pub fn use_on_create<T, F>(future: impl FnOnce() -> F)
 where
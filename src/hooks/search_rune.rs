@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
 
+use data::Query;
 use data::Repository;
 use data::Rune;
 use data::RuneHash;
@@ -10,11 +11,14 @@ use crate::global::{DATABASE, SEARCH_CATALOGS};
 pub struct UseSearchRune {
     pub query: Signal<String>,
     pub results: Signal<Vec<Signal<Rune>>>,
+    /// Terms of the query to highlight in the results.
+    pub terms: Memo<Vec<String>>,
 }
 
 pub fn use_search_rune() -> UseSearchRune {
     let query = use_signal(|| String::new());
     let mut results = use_signal(|| Vec::<Signal<Rune>>::new());
+    let terms = use_memo(move || Query::parse(&query.read()).highlight_terms());
 
     use_effect(move || {
         let hashes: Vec<RuneHash> = if query().is_empty() {
@@ -35,5 +39,9 @@ pub fn use_search_rune() -> UseSearchRune {
         *results.write() = items;
     });
 
-    use_hook(|| UseSearchRune { query, results })
+    use_hook(|| UseSearchRune {
+        query,
+        results,
+        terms,
+    })
 }
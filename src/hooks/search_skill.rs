@@ -2,16 +2,20 @@ use dioxus::prelude::*;
 
 use crate::global::{DATABASE, SEARCH_CATALOGS};
 use data::skill::{Skill, SkillHash};
+use data::Query;
 
 #[derive(PartialEq, Clone)]
 pub struct UseSearchSkill {
     pub query: Signal<String>,
     pub results: Signal<Vec<Signal<Skill>>>,
+    /// Terms of the query to highlight in the results.
+    pub terms: Memo<Vec<String>>,
 }
 
 pub fn use_search_skill() -> UseSearchSkill {
     let query = use_signal(|| String::new());
     let mut results = use_signal(|| Vec::<Signal<Skill>>::new());
+    let terms = use_memo(move || Query::parse(&query.read()).highlight_terms());
 
     use_effect(move || {
         let hashes: Vec<SkillHash> = if query().is_empty() {
@@ -32,5 +36,9 @@ pub fn use_search_skill() -> UseSearchSkill {
         *results.write() = items;
     });
 
-    use_hook(|| UseSearchSkill { query, results })
+    use_hook(|| UseSearchSkill {
+        query,
+        results,
+        terms,
+    })
 }
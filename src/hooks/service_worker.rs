@@ -0,0 +1,33 @@
+use dioxus::prelude::*;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "/src/bundle.js")]
+extern "C" {
+    #[wasm_bindgen(js_name = registerServiceWorker)]
+    fn register_service_worker(on_data_updated: &Closure<dyn FnMut(String)>);
+
+    #[wasm_bindgen(js_name = precacheDatabase)]
+    fn precache_database_js(lang: &str, version: &str, files: js_sys::Array);
+}
+
+/// Registers the service worker, and returns the language whose database has been
+/// updated since it was loaded.
+pub fn use_service_worker() -> Signal<Option<String>> {
+    let mut updated = use_signal(|| None::<String>);
+    use_hook(|| {
+        let closure = Closure::<dyn FnMut(String)>::new(move |lang: String| {
+            *updated.write() = Some(lang);
+        });
+        register_service_worker(&closure);
+        // Lives as long as the page
+        closure.forget();
+    });
+    updated
+}
+
+/// Keeps the files of the database of `lang` available offline, replacing the other
+/// versions.
+pub fn precache_database(lang: &str, version: &str, files: &[String]) {
+    let files = files.iter().map(|f| JsValue::from_str(f)).collect();
+    precache_database_js(lang, version, files);
+}
@@ -1,11 +1,13 @@
 pub use app::App;
 use derive_more::{Deref, DerefMut, Display, From, FromStr, Into};
 
+mod api;
 mod app;
 mod components;
 mod editor;
 mod global;
 mod hooks;
+mod markdown;
 mod pages;
 mod search;
 mod ui;
@@ -13,3 +15,16 @@ mod worker;
 
 #[derive(Debug, Clone, PartialEq, Display, From, FromStr, Into, Deref, DerefMut)]
 pub struct Language(String);
+
+impl Language {
+    /// Whether the database has been generated for this language.
+    pub fn is_supported(&self) -> bool {
+        data::LANGUAGES.contains(&self.0.as_str())
+    }
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Language("en".to_string())
+    }
+}
@@ -0,0 +1,92 @@
+use markdown_it::parser::core::CoreRule;
+use markdown_it::plugins::cmark::block::fence::CodeFence;
+use markdown_it::{MarkdownIt, Node, NodeValue, Renderer};
+
+use build_format::{references, Reference, ReferenceKind};
+
+use crate::pages::planner::PlannerState;
+
+/// ```` ```build ```` fenced block holding a planner share string,
+/// rendered with the planner slots.
+///
+/// ````markdown
+/// ```build
+/// <share string from the planner URL>
+/// ```
+/// ````
+#[derive(Debug)]
+pub struct BuildLayout(pub PlannerState);
+
+impl NodeValue for BuildLayout {
+    fn render(&self, _: &Node, fmt: &mut dyn Renderer) {
+        fmt.open("div", &[("data-planner-state", self.0.to_string())]);
+        fmt.close("div");
+        fmt.cr();
+    }
+}
+
+/// ```` ```skill-card ```` fenced block, rendered as a full skill view.
+/// The content is a `<skill:...>` reference or a bare skill id or name.
+#[derive(Debug)]
+pub struct SkillCard(pub Reference);
+
+impl NodeValue for SkillCard {
+    fn render(&self, _: &Node, fmt: &mut dyn Renderer) {
+        fmt.open("div", &[("data-reference", self.0.to_string())]);
+        fmt.text(&self.0.key);
+        fmt.close("div");
+        fmt.cr();
+    }
+}
+
+fn parse_build(content: &str) -> Option<BuildLayout> {
+    content.trim().parse::<PlannerState>().ok().map(BuildLayout)
+}
+
+fn parse_skill_card(content: &str) -> Option<SkillCard> {
+    let content = content.trim();
+    if content.is_empty() {
+        return None;
+    }
+    let reference = references(content)
+        .into_iter()
+        .find(|r| r.kind == ReferenceKind::Skill)
+        .unwrap_or_else(|| Reference {
+            kind: ReferenceKind::Skill,
+            key: content.to_string(),
+        });
+    Some(SkillCard(reference))
+}
+
+struct FencedBlockRule;
+
+impl CoreRule for FencedBlockRule {
+    fn run(root: &mut Node, _: &MarkdownIt) {
+        root.walk_mut(|node, _| {
+            let Some(fence) = node.cast::<CodeFence>() else {
+                return;
+            };
+            let info = fence.info.trim().to_string();
+            let content = fence.content.clone();
+            match info.as_str() {
+                "build" => {
+                    // Anything but a share string stays a code block
+                    if let Some(layout) = parse_build(&content) {
+                        node.replace(layout);
+                    }
+                }
+                "skill-card" => {
+                    if let Some(card) = parse_skill_card(&content) {
+                        node.replace(card);
+                    }
+                }
+                _ => (),
+            }
+        });
+    }
+}
+
+/// Adds the ```` ```build ```` and ```` ```skill-card ```` fenced blocks.
+pub fn add(md: &mut MarkdownIt) {
+    md.add_rule::<FencedBlockRule>();
+}
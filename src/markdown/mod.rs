@@ -0,0 +1,19 @@
+use markdown_it::plugins::{cmark, extra};
+use markdown_it::MarkdownIt;
+
+pub use self::block::*;
+pub use self::reference::*;
+
+mod block;
+mod reference;
+
+/// Creates the markdown parser used for build documents.
+pub fn parser() -> MarkdownIt {
+    let mut md = MarkdownIt::new();
+    cmark::add(&mut md);
+    extra::strikethrough::add(&mut md);
+    extra::tables::add(&mut md);
+    reference::add(&mut md);
+    block::add(&mut md);
+    md
+}
@@ -0,0 +1,88 @@
+use markdown_it::parser::inline::{InlineRule, InlineState};
+use markdown_it::plugins::cmark::inline::autolink::AutolinkScanner;
+use markdown_it::{MarkdownIt, Node, NodeValue, Renderer};
+
+use build_format::Reference;
+
+/// `<skill:...>`, `<rune:...>`, `<state:...>` or `<enemy:...>` reference to a game entity.
+#[derive(Debug)]
+pub struct EntityReference(pub Reference);
+
+impl NodeValue for EntityReference {
+    fn render(&self, _: &Node, fmt: &mut dyn Renderer) {
+        let attrs = [("data-reference", self.0.to_string())];
+        fmt.open("span", &attrs);
+        fmt.text(&self.0.key);
+        fmt.close("span");
+    }
+}
+
+struct ReferenceScanner;
+
+impl InlineRule for ReferenceScanner {
+    const MARKER: char = '<';
+
+    fn run(state: &mut InlineState) -> Option<(Node, usize)> {
+        let input = &state.src[state.pos..state.pos_max];
+        let (reference, len) = Reference::parse_prefix(input)?;
+        Some((Node::new(EntityReference(reference)), len))
+    }
+}
+
+/// Adds the reference syntax. It takes precedence over autolinks, which share the `<` marker.
+pub fn add(md: &mut MarkdownIt) {
+    md.inline
+        .add_rule::<ReferenceScanner>()
+        .before::<AutolinkScanner>();
+}
+
+#[cfg(test)]
+mod test {
+    use build_format::ReferenceKind;
+
+    use super::*;
+    use crate::markdown::parser;
+
+    fn references(src: &str) -> Vec<Reference> {
+        let mut out = vec![];
+        parser().parse(src).walk(|node, _| {
+            if let Some(r) = node.cast::<EntityReference>() {
+                out.push(r.0.clone());
+            }
+        });
+        out
+    }
+
+    #[test]
+    fn test_reference() {
+        assert_eq!(
+            references("<skill:Energy Slash> and <enemy:Slime>"),
+            vec![
+                Reference {
+                    kind: ReferenceKind::Skill,
+                    key: "Energy Slash".to_string()
+                },
+                Reference {
+                    kind: ReferenceKind::Enemy,
+                    key: "Slime".to_string()
+                },
+            ]
+        );
+        assert_eq!(references("`<skill:abc>`"), vec![]);
+        assert_eq!(references("<skill:a\nbc>"), vec![]);
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            parser().parse("<rune:Ruby> <https://example.com>").render(),
+            "<p><span data-reference=\"&lt;rune:Ruby&gt;\">Ruby</span> \
+             <a href=\"https://example.com\">https://example.com</a></p>\n"
+        );
+        // Unknown kinds are still autolinks
+        assert_eq!(
+            parser().parse("<item:abc>").render(),
+            "<p><a href=\"item:abc\">item:abc</a></p>\n"
+        );
+    }
+}
@@ -1,10 +1,37 @@
 import {EditorView, basicSetup} from "codemirror";
 import {EditorState} from "@codemirror/state";
+import {hoverTooltip} from "@codemirror/view";
+import {autocompletion} from "@codemirror/autocomplete";
+import {linter} from "@codemirror/lint";
 import {markdown} from "@codemirror/lang-markdown";
 import {basicDark} from "cm6-theme-basic-dark";
 
+// The lowest atlas resolution covering the device pixels, as in `src/ui/sprite.rs`.
+function spriteResolution(scale) {
+    const pixels = scale * (window.devicePixelRatio || 1);
+    return [1, 2, 4].find((r) => r >= pixels) || 4;
+}
+
+function spriteElement(sprite, size) {
+    const scale = size / Math.max(sprite.width, sprite.height);
+    const outer = document.createElement("span");
+    outer.className = "inline-block bg-black overflow-hidden rounded-md align-middle mr-1";
+    outer.style.width = `${size}px`;
+    outer.style.height = `${size}px`;
+    outer.style.lineHeight = `${size}px`;
+    const inner = document.createElement("span");
+    inner.className = `inline-block sprite sprite-${sprite.index} sprite-${spriteResolution(scale)}x`;
+    inner.style.cssText = `--x: ${sprite.x}px; --y: ${sprite.y}px; --w: ${sprite.width}px; --h: ${sprite.height}px; --s: ${scale}`;
+    outer.appendChild(inner);
+    return outer;
+}
+
 export class CodeMirror {
     constructor(parent, onChange) {
+        this.completionSource = null;
+        this.hoverSource = null;
+        this.lintSource = null;
+
         const updateListener = EditorView.updateListener.of((vu) => {
             if (vu.docChanged) {
                 const doc = vu.state.doc;
@@ -19,8 +46,80 @@ export class CodeMirror {
             }
         });
 
+        const completion = autocompletion({
+            override: [(context) => {
+                const match = context.matchBefore(/<(skill|rune):[^<>\n]*/);
+                if (!match || !this.completionSource) {
+                    return null;
+                }
+                const [, kind] = match.text.match(/^<(skill|rune):/);
+                const query = match.text.slice(kind.length + 2);
+                const options = this.completionSource(kind, query) || [];
+                const next = context.state.sliceDoc(context.pos, context.pos + 1);
+                return {
+                    from: match.from,
+                    to: next === ">" ? context.pos + 1 : context.pos,
+                    filter: false,
+                    options: options.map((o) => ({
+                        label: o.label,
+                        detail: o.detail,
+                        apply: o.apply,
+                        sprite: o.sprite,
+                    })),
+                };
+            }],
+            addToOptions: [{
+                render: (completion) => spriteElement(completion.sprite, 20),
+                position: 20,
+            }],
+        });
+
+        const hover = hoverTooltip((view, pos) => {
+            if (!this.hoverSource) {
+                return null;
+            }
+            const line = view.state.doc.lineAt(pos);
+            const info = this.hoverSource(line.text, pos - line.from);
+            if (!info) {
+                return null;
+            }
+            return {
+                pos: line.from + info.from,
+                end: line.from + info.to,
+                above: true,
+                create: () => {
+                    const dom = document.createElement("div");
+                    dom.className = "p-2 max-w-md";
+                    const title = document.createElement("div");
+                    title.className = "font-bold mb-1";
+                    if (info.sprite) {
+                        title.appendChild(spriteElement(info.sprite, 24));
+                    }
+                    title.appendChild(document.createTextNode(info.title));
+                    const description = document.createElement("div");
+                    description.className = "whitespace-pre-wrap text-sm";
+                    description.textContent = info.description;
+                    dom.append(title, description);
+                    return {dom};
+                },
+            };
+        });
+
+        const lint = linter((view) => {
+            if (!this.lintSource) {
+                return [];
+            }
+            const diagnostics = this.lintSource(view.state.doc.toString()) || [];
+            return diagnostics.map((d) => ({
+                from: d.from,
+                to: d.to,
+                severity: "error",
+                message: d.message,
+            }));
+        });
+
         const state = EditorState.create({
-            extensions: [basicSetup, markdown(), basicDark, updateListener, height],
+            extensions: [basicSetup, markdown(), basicDark, updateListener, height, completion, hover, lint],
         });
 
         this.view = new EditorView({
@@ -36,4 +135,40 @@ export class CodeMirror {
     set value(value) {
         this.view.dispatch({changes: {from: 0, to: this.view.state.doc.length, insert: value}});
     }
-}
\ No newline at end of file
+
+    setCompletionSource(source) {
+        this.completionSource = source;
+    }
+
+    setHoverSource(source) {
+        this.hoverSource = source;
+    }
+
+    setLintSource(source) {
+        this.lintSource = source;
+    }
+}
+
+// Registers `/sw.js`, which exists in release builds only. `onDataUpdated(lang)` is called
+// when the worker has fetched a newer database than the one shown.
+export function registerServiceWorker(onDataUpdated) {
+    if (!("serviceWorker" in navigator)) {
+        return;
+    }
+    navigator.serviceWorker.addEventListener("message", (event) => {
+        if (event.data && event.data.type === "data-updated") {
+            onDataUpdated(event.data.lang);
+        }
+    });
+    navigator.serviceWorker.register("/sw.js").catch((e) => console.info("service worker:", e));
+}
+
+// Asks the service worker to cache the files of a database for offline use.
+export function precacheDatabase(lang, version, files) {
+    if (!("serviceWorker" in navigator)) {
+        return;
+    }
+    navigator.serviceWorker.ready.then((registration) => {
+        registration.active?.postMessage({type: "precache", lang, version, files});
+    });
+}
@@ -0,0 +1,462 @@
+use dioxus::prelude::*;
+use dioxus::router::router;
+use dioxus::web::WebEventExt;
+use headlessui::{
+    RenderFn, Tab, TabGroup, TabList, TabPanel, TabPanelRenderArgs, TabPanels, TabRenderArgs,
+};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::closure::Closure;
+
+use build_format::api::PutViewerBuildRequest;
+use build_format::{BuildExport, BuildMetadata, Import};
+use classes::classes;
+
+use super::render::{render_markdown, DetailDialog, RenderArgs};
+use crate::api::{use_api_client, ApiError};
+use crate::editor::{install_providers, CodeMirror};
+use crate::global::{DATABASE, TERM_NAMES};
+use crate::hooks::use_persistent;
+use crate::pages::Route;
+use crate::Language;
+
+const SAMPLE: &str = "<skill:エンシェントシールド> 強い。 <skill:インビンシブル> <skill:デーモンバイト> ドロップしない？ <skill:採掘> 便利";
+
+/// Unsaved edits, kept in local storage until the build is saved.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct Draft {
+    body: String,
+    metadata: BuildMetadata,
+    /// `updated_at` of the saved version the draft is based on.
+    base_updated_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Conflict {
+    /// The local draft is based on an older version than the saved build.
+    Draft,
+    /// Saving was rejected because the build changed elsewhere.
+    Save,
+}
+
+#[component]
+pub fn BuildEditPage(language: Language) -> Element {
+    rsx! {
+        BuildEditor { key: "new", language, build_id: None }
+    }
+}
+
+#[component]
+pub fn BuildPage(language: Language, build_id: String) -> Element {
+    rsx! {
+        BuildEditor { key: "{build_id}", language, build_id: Some(build_id.clone()) }
+    }
+}
+
+#[component]
+fn BuildEditor(language: Language, build_id: Option<String>) -> Element {
+    let detail_open = use_signal(|| false);
+    let detail_target = use_signal(|| None);
+
+    let api = use_api_client();
+    let draft_key = format!("build_draft:{}", build_id.as_deref().unwrap_or("new"));
+    let draft = use_persistent(draft_key, || None::<Draft>);
+    let initial_draft = use_hook(|| draft.get());
+    let sample = if build_id.is_none() { SAMPLE } else { "" };
+    let build_id = use_signal(|| build_id);
+
+    let mut doc = use_signal(|| {
+        initial_draft
+            .as_ref()
+            .map(|d| d.body.clone())
+            .unwrap_or_else(|| sample.to_string())
+    });
+    let mut metadata = use_signal(|| {
+        initial_draft
+            .as_ref()
+            .map(|d| d.metadata.clone())
+            .unwrap_or_default()
+    });
+    let mut base_updated_at = use_signal(|| initial_draft.as_ref().and_then(|d| d.base_updated_at));
+    // Body and metadata of the last loaded or saved version
+    let mut saved = use_signal(|| None::<(String, BuildMetadata)>);
+    let mut conflict = use_signal(|| None::<Conflict>);
+    let mut saving = use_signal(|| false);
+    let mut save_error = use_signal(|| None::<String>);
+    let mut import_result = use_signal(|| None::<Result<Import, String>>);
+
+    let loaded = use_resource(move || async move {
+        match build_id() {
+            Some(id) => Some(api.get_build(&id).await),
+            None => None,
+        }
+    });
+    use_effect(move || {
+        if let Some(Some(Ok(build))) = loaded.read().as_ref() {
+            let updated_at = build.metadata.updated_at;
+            let remote = (build.value.body.clone(), build.metadata.clone());
+            match initial_draft.as_ref() {
+                Some(d) if d.base_updated_at == Some(updated_at) => {
+                    *base_updated_at.write() = Some(updated_at);
+                }
+                Some(d) if d.body != remote.0 => {
+                    *conflict.write() = Some(Conflict::Draft);
+                }
+                _ => {
+                    *doc.write() = remote.0.clone();
+                    *metadata.write() = remote.1.clone();
+                    *base_updated_at.write() = Some(updated_at);
+                }
+            }
+            *saved.write() = Some(remote);
+        }
+    });
+
+    let dirty = use_memo(move || match saved() {
+        Some((body, m)) => {
+            body != doc() || m.title != metadata().title || m.is_private != metadata().is_private
+        }
+        None => true,
+    });
+
+    // Autosave the draft on every change, once a saved build has been loaded.
+    // A draft matching the saved build is dropped, so it can't conflict later.
+    let mut autosave = draft.clone();
+    use_effect(move || {
+        if build_id().is_some() && saved().is_none() {
+            return;
+        }
+        if !dirty() {
+            autosave.set(None);
+            return;
+        }
+        autosave.set(Some(Draft {
+            body: doc(),
+            metadata: metadata(),
+            base_updated_at: base_updated_at(),
+        }));
+    });
+
+    let l = language.clone();
+    let save = move |overwrite: bool| {
+        let l = l.clone();
+        let mut draft = draft.clone();
+        spawn(async move {
+            *saving.write() = true;
+            *save_error.write() = None;
+            let body = doc();
+            match build_id() {
+                None => match api.create_build(body, metadata()).await {
+                    Ok(id) => {
+                        draft.set(None);
+                        router().replace(Route::BuildPage {
+                            language: l,
+                            build_id: id,
+                        });
+                    }
+                    Err(err) => *save_error.write() = Some(err.to_string()),
+                },
+                Some(id) => {
+                    let req = PutViewerBuildRequest {
+                        body: body.clone(),
+                        metadata: metadata(),
+                        base_updated_at: if overwrite { None } else { base_updated_at() },
+                    };
+                    match api.update_build(&id, &req).await {
+                        Ok(m) => {
+                            draft.set(None);
+                            *base_updated_at.write() = Some(m.updated_at);
+                            *metadata.write() = m.clone();
+                            *saved.write() = Some((body, m));
+                            *conflict.write() = None;
+                        }
+                        Err(ApiError::Conflict) => *conflict.write() = Some(Conflict::Save),
+                        Err(err) => *save_error.write() = Some(err.to_string()),
+                    }
+                }
+            }
+            *saving.write() = false;
+        });
+    };
+
+    let l = language.clone();
+    let export =
+        use_memo(move || BuildExport::new(&DATABASE(), &TERM_NAMES.read(), &l, metadata(), doc()));
+    let l = language.clone();
+
+    let mut code_mirror = use_signal(|| None::<CodeMirror>);
+
+    use_effect(move || {
+        if let Some(ref cm) = *code_mirror.read() {
+            if doc() != cm.value() {
+                cm.set_value(doc());
+            }
+        }
+    });
+
+    let rendered = use_memo(move || {
+        let md = crate::markdown::parser();
+        let node = md.parse(&doc());
+        // tracing::info!("{:#?}", node);
+        render_markdown(RenderArgs {
+            node: &node,
+            language: &l,
+            open: detail_open.clone(),
+            target: detail_target.clone(),
+        })
+    });
+
+    let render_tab = RenderFn::<TabRenderArgs>::new(move |args| {
+        let class = classes!["tab", "tab-active [--tab-bg:oklch(var(--b2))]" => args.selected];
+        rsx! {
+            a {
+                class,
+                ..args.attrs,
+                {args.children}
+            }
+        }
+    });
+    let render_tab_panel = RenderFn::<TabPanelRenderArgs>::new(move |args| {
+        let class = classes!["bg-base-200 rounded-b-lg p-4", "hidden" => !args.selected];
+        rsx! {
+            div {
+                class,
+                ..args.attrs,
+                {args.children}
+            }
+        }
+    });
+
+    let mut use_saved = move || {
+        if let Some((body, m)) = saved() {
+            *base_updated_at.write() = Some(m.updated_at);
+            *doc.write() = body;
+            *metadata.write() = m;
+        }
+        *conflict.write() = None;
+    };
+    let reload = move || {
+        spawn(async move {
+            if let Some(id) = build_id() {
+                match api.get_build(&id).await {
+                    Ok(build) => {
+                        *saved.write() = Some((build.value.body, build.metadata));
+                        use_saved();
+                    }
+                    Err(err) => *save_error.write() = Some(err.to_string()),
+                }
+            }
+        });
+    };
+
+    let mut discard = draft.clone();
+
+    let save_title = if api.is_authenticated() {
+        ""
+    } else {
+        "Log in to save builds"
+    };
+
+    rsx! {
+        div { class: "flex flex-row flex-wrap items-center gap-2 mb-2",
+            input {
+                class: "input input-bordered input-sm flex-grow",
+                placeholder: "Title",
+                value: "{metadata().title}",
+                oninput: move |e| metadata.write().title = e.value()
+            }
+            label { class: "label cursor-pointer gap-2",
+                span { class: "label-text", "Private" }
+                input {
+                    class: "toggle toggle-sm",
+                    r#type: "checkbox",
+                    checked: metadata().is_private,
+                    onchange: move |_| {
+                        let is_private = !metadata().is_private;
+                        metadata.write().is_private = is_private;
+                    }
+                }
+            }
+            if dirty() {
+                span { class: "badge badge-ghost", "Unsaved changes" }
+            }
+            button {
+                class: "btn btn-sm btn-primary",
+                disabled: saving() || !api.is_authenticated(),
+                title: save_title,
+                onclick: {
+                    let save = save.clone();
+                    move |_| save(false)
+                },
+                if saving() {
+                    span { class: "loading loading-spinner loading-xs" }
+                }
+                "Save"
+            }
+        }
+        if let Some(err) = save_error() {
+            div { role: "alert", class: "alert alert-error mb-2",
+                span { "Failed to save: {err}" }
+                button { class: "btn btn-sm btn-ghost", onclick: move |_| *save_error.write() = None, "Close" }
+            }
+        }
+        match conflict() {
+            Some(Conflict::Draft) => rsx! {
+                div { role: "alert", class: "alert alert-warning mb-2",
+                    span { "You have a local draft based on an older version of this build." }
+                    button {
+                        class: "btn btn-sm",
+                        onclick: move |_| {
+                            *base_updated_at.write() = saved().map(|(_, m)| m.updated_at);
+                            *conflict.write() = None;
+                        },
+                        "Keep draft"
+                    }
+                    button {
+                        class: "btn btn-sm btn-ghost",
+                        onclick: move |_| {
+                            discard.set(None);
+                            use_saved();
+                        },
+                        "Discard draft"
+                    }
+                }
+            },
+            Some(Conflict::Save) => rsx! {
+                div { role: "alert", class: "alert alert-warning mb-2",
+                    span { "This build was updated elsewhere since you opened it." }
+                    button {
+                        class: "btn btn-sm",
+                        onclick: {
+                            let save = save.clone();
+                            move |_| save(true)
+                        },
+                        "Overwrite"
+                    }
+                    button { class: "btn btn-sm btn-ghost", onclick: move |_| reload(), "Reload" }
+                }
+            },
+            None => None,
+        }
+        div { class: "flex flex-row gap-2 mb-2",
+            a {
+                class: "btn btn-sm",
+                href: data_url("application/json", &export().to_json()),
+                download: "build.json",
+                "Export JSON"
+            }
+            a {
+                class: "btn btn-sm",
+                href: data_url("text/markdown", &export().to_markdown()),
+                download: "build.md",
+                "Export Markdown"
+            }
+            label { class: "btn btn-sm",
+                "Import"
+                input {
+                    class: "hidden",
+                    r#type: "file",
+                    accept: ".json,application/json",
+                    onchange: move |e| async move {
+                        if let Some(files) = e.files() {
+                            for name in files.files() {
+                                if let Some(s) = files.read_file_to_string(&name).await {
+                                    match BuildExport::from_json(&s) {
+                                        Ok(export) => {
+                                            let import = export.import(&DATABASE(), &TERM_NAMES.read());
+                                            *doc.write() = import.body.clone();
+                                            *metadata.write() = import.metadata.clone();
+                                            *import_result.write() = Some(Ok(import));
+                                        }
+                                        Err(err) => {
+                                            *import_result.write() = Some(Err(err.to_string()));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        ImportResult { result: import_result }
+
+        TabGroup {
+            TabList {
+                class: "tabs-lifted",
+                Tab {
+                    index: 0,
+                    render: Some(render_tab.clone()),
+                    "Write"
+                }
+                Tab {
+                    index: 1,
+                    render: Some(render_tab.clone()),
+                    "Preview"
+                }
+            }
+            TabPanels {
+                TabPanel {
+                    index: 0,
+                    r#static: true,
+                    render: Some(render_tab_panel.clone()),
+                    div {
+                        class: "w-full h-96",
+                        onmounted: move |e| {
+                            let parent = e.web_event();
+                            let on_change = Closure::wrap(
+                                Box::new(move |value| {
+                                    *doc.write() = value;
+                                }) as Box<dyn FnMut(String)>,
+                            );
+                            let cm = CodeMirror::new(parent, &on_change);
+                            on_change.forget();
+                            install_providers(&cm);
+                            *code_mirror.write() = Some(cm);
+                        }
+                    }
+                }
+                TabPanel {
+                    index: 1,
+                    render: Some(render_tab_panel.clone()),
+                    article { class: "prose md:prose-lg max-w-full", {rendered()} }
+                }
+            }
+        }
+
+        DetailDialog { language open: detail_open, target: detail_target }
+    }
+}
+
+fn data_url(mime: &str, content: &str) -> String {
+    let encoded = String::from(js_sys::encode_uri_component(content));
+    format!("data:{};charset=utf-8,{}", mime, encoded)
+}
+
+#[component]
+fn ImportResult(result: Signal<Option<Result<Import, String>>>) -> Element {
+    match result.read().as_ref() {
+        Some(Ok(import)) if !import.is_complete() => {
+            let names = import
+                .unknown_skills
+                .iter()
+                .chain(import.unknown_runes.iter())
+                .chain(import.unknown_terms.iter())
+                .map(|e| e.name.clone())
+                .collect::<Vec<_>>()
+                .join(", ");
+            rsx! {
+                div { role: "alert", class: "alert alert-warning mb-2",
+                    span { "Imported with unknown references: {names}" }
+                    button { class: "btn btn-sm btn-ghost", onclick: move |_| *result.write() = None, "Close" }
+                }
+            }
+        }
+        Some(Err(err)) => rsx! {
+            div { role: "alert", class: "alert alert-error mb-2",
+                span { "Failed to import: {err}" }
+                button { class: "btn btn-sm btn-ghost", onclick: move |_| *result.write() = None, "Close" }
+            }
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,97 @@
+use dioxus::prelude::*;
+use wasm_bindgen::JsValue;
+
+use build_format::BuildMetadata;
+
+use crate::api::use_api_client;
+use crate::pages::Route;
+use crate::Language;
+
+#[component]
+pub fn BuildListPage(language: Language) -> Element {
+    let api = use_api_client();
+    let builds = use_resource(move || async move { api.list_builds().await });
+
+    rsx! {
+        div { class: "text-sm breadcrumbs",
+            ul {
+                li { "Home" }
+                li { "My builds" }
+            }
+        }
+
+        div { class: "flex flex-row justify-end mb-2",
+            Link {
+                class: "btn btn-sm btn-primary",
+                to: Route::BuildEditPage { language: language.clone() },
+                "New build"
+            }
+        }
+
+        match builds.read().as_ref() {
+            None => rsx! {
+                span { class: "loading loading-spinner" }
+            },
+            Some(Err(err)) => rsx! {
+                div { role: "alert", class: "alert alert-error", "Failed to load builds: {err}" }
+            },
+            Some(Ok(items)) if items.is_empty() => rsx! {
+                p { "You have no saved builds yet." }
+            },
+            Some(Ok(items)) => {
+                let mut items = items.clone();
+                items.sort_by(|a, b| b.1.updated_at.cmp(&a.1.updated_at));
+                rsx! {
+                    table { class: "table",
+                        thead {
+                            tr {
+                                th { "Title" }
+                                th { "Visibility" }
+                                th { "Updated" }
+                            }
+                        }
+                        tbody {
+                            for (id , metadata) in items {
+                                BuildListItem { language: language.clone(), id, metadata }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn BuildListItem(language: Language, id: String, metadata: BuildMetadata) -> Element {
+    let title = if metadata.title.is_empty() {
+        "Untitled".to_string()
+    } else {
+        metadata.title.clone()
+    };
+    let updated_at = js_sys::Date::new(&JsValue::from_f64(metadata.updated_at as f64))
+        .to_locale_string("default", &JsValue::UNDEFINED);
+
+    rsx! {
+        tr { class: "hover",
+            td {
+                Link {
+                    class: "link link-hover",
+                    to: Route::BuildPage {
+                        language,
+                        build_id: id,
+                    },
+                    "{title}"
+                }
+            }
+            td {
+                if metadata.is_private {
+                    span { class: "badge badge-ghost", "Private" }
+                } else {
+                    span { class: "badge badge-primary", "Public" }
+                }
+            }
+            td { "{updated_at}" }
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use dioxus::prelude::*;
+use markdown_it::parser;
+use markdown_it::plugins::{cmark, extra, html};
+use markdown_it::Node;
+
+use build_format::{Reference, ReferenceKind};
+
+use crate::components::{RuneView, SkillView};
+use crate::global::{DATABASE, TERM_NAMES};
+use crate::markdown::{BuildLayout, EntityReference, SkillCard};
+use crate::pages::planner::PlannerSlot;
+use crate::ui::{Description, Dialog, SpriteIcon, Tooltip};
+use crate::Language;
+
+pub struct RenderArgs<'a> {
+    pub node: &'a Node,
+    pub language: &'a Language,
+    pub open: Signal<bool>,
+    pub target: Signal<Option<DetailTarget>>,
+}
+
+impl<'a> RenderArgs<'a> {
+    fn with(&self, node: &'a Node) -> RenderArgs<'a> {
+        RenderArgs {
+            node,
+            language: self.language,
+            open: self.open,
+            target: self.target,
+        }
+    }
+
+    fn render_children(&self) -> Element {
+        rsx! {{self.node.children.iter().map(|child| render_markdown(self.with(child)))}}
+    }
+}
+
+pub fn render_markdown(args: RenderArgs) -> Element {
+    let node = args.node;
+    if let Some(_) = node.cast::<parser::core::Root>() {
+        args.render_children()
+    } else if let Some(n) = node.cast::<parser::inline::Text>() {
+        rsx! {"{n.content}"}
+    } else if let Some(n) = node.cast::<parser::inline::TextSpecial>() {
+        rsx! {"{n.content}"}
+    } else if let Some(n) = node.cast::<EntityReference>() {
+        render_reference(&n.0, args.open, args.target)
+    } else if let Some(n) = node.cast::<BuildLayout>() {
+        rsx! {
+            div { class: "not-prose flex flex-col gap-2 my-4",
+                for (i , slot) in n.0.slots().iter().enumerate() {
+                    PlannerSlot { language: args.language.clone(), index: i as i32, state: slot.clone() }
+                }
+            }
+        }
+    } else if let Some(n) = node.cast::<SkillCard>() {
+        match n.0.resolve_skill(&DATABASE()) {
+            Some(skill) => rsx! {
+                div { class: "not-prose",
+                    SkillView { language: args.language.clone(), skill: Signal::new(skill.clone()) }
+                }
+            },
+            None => render_reference(&n.0, args.open, args.target),
+        }
+    } else if let Some(n) = node.cast::<cmark::block::heading::ATXHeading>() {
+        render_heading(n.level, args)
+    } else if let Some(n) = node.cast::<cmark::block::lheading::SetextHeader>() {
+        render_heading(n.level, args)
+    } else if let Some(_) = node.cast::<cmark::block::paragraph::Paragraph>() {
+        rsx! {
+            p { {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<cmark::block::blockquote::Blockquote>() {
+        rsx! {
+            blockquote { {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<cmark::block::hr::ThematicBreak>() {
+        rsx! { hr {} }
+    } else if let Some(n) = node.cast::<cmark::block::code::CodeBlock>() {
+        rsx! {
+            pre { code { "{n.content}" } }
+        }
+    } else if let Some(n) = node.cast::<cmark::block::fence::CodeFence>() {
+        let lang = n.info.split_whitespace().next().unwrap_or("");
+        rsx! {
+            pre {
+                code { class: "language-{lang}", "{n.content}" }
+            }
+        }
+    } else if let Some(_) = node.cast::<cmark::block::list::BulletList>() {
+        rsx! {
+            ul { {args.render_children()} }
+        }
+    } else if let Some(n) = node.cast::<cmark::block::list::OrderedList>() {
+        rsx! {
+            ol { start: "{n.start}", {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<cmark::block::list::ListItem>() {
+        rsx! {
+            li { {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<extra::tables::Table>() {
+        rsx! {
+            div { class: "overflow-x-auto",
+                table { class: "table", {args.render_children()} }
+            }
+        }
+    } else if let Some(_) = node.cast::<extra::tables::TableHead>() {
+        // Header cells are rendered as `th`, so walk the rows here.
+        rsx! {
+            thead {
+                for row in node.children.iter() {
+                    tr {
+                        for cell in row.children.iter() {
+                            th { {args.with(cell).render_children()} }
+                        }
+                    }
+                }
+            }
+        }
+    } else if let Some(_) = node.cast::<extra::tables::TableBody>() {
+        rsx! {
+            tbody { {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<extra::tables::TableRow>() {
+        rsx! {
+            tr { {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<extra::tables::TableCell>() {
+        rsx! {
+            td { {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<cmark::inline::newline::Softbreak>() {
+        rsx! { br {} }
+    } else if let Some(_) = node.cast::<cmark::inline::newline::Hardbreak>() {
+        rsx! { br {} }
+    } else if let Some(_) = node.cast::<cmark::inline::emphasis::Em>() {
+        rsx! {
+            em { {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<cmark::inline::emphasis::Strong>() {
+        rsx! {
+            strong { {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<extra::strikethrough::Strikethrough>() {
+        rsx! {
+            s { {args.render_children()} }
+        }
+    } else if let Some(_) = node.cast::<cmark::inline::backticks::CodeInline>() {
+        rsx! {
+            code { {args.render_children()} }
+        }
+    } else if let Some(n) = node.cast::<cmark::inline::link::Link>() {
+        rsx! {
+            a {
+                href: "{n.url}",
+                title: n.title.clone(),
+                target: "_blank",
+                rel: "noopener noreferrer",
+                {args.render_children()}
+            }
+        }
+    } else if let Some(n) = node.cast::<cmark::inline::image::Image>() {
+        rsx! {
+            img {
+                src: "{n.url}",
+                alt: node.collect_text(),
+                title: n.title.clone()
+            }
+        }
+    } else if let Some(n) = node.cast::<cmark::inline::autolink::Autolink>() {
+        rsx! {
+            a { href: "{n.url}", target: "_blank", rel: "noopener noreferrer", {args.render_children()} }
+        }
+    } else if let Some(n) = node.cast::<html::html_block::HtmlBlock>() {
+        // Raw HTML is shown as text, never injected into the page
+        rsx! {
+            p { "{n.content}" }
+        }
+    } else if let Some(n) = node.cast::<html::html_inline::HtmlInline>() {
+        rsx! {"{n.content}"}
+    } else {
+        tracing::warn!("Unknown node: {:#?}", node);
+        None
+    }
+}
+
+fn render_heading(level: u8, args: RenderArgs) -> Element {
+    let children = args.render_children();
+    match level {
+        1 => rsx! {
+            h1 { {children} }
+        },
+        2 => rsx! {
+            h2 { {children} }
+        },
+        3 => rsx! {
+            h3 { {children} }
+        },
+        4 => rsx! {
+            h4 { {children} }
+        },
+        5 => rsx! {
+            h5 { {children} }
+        },
+        _ => rsx! {
+            h6 { {children} }
+        },
+    }
+}
+
+fn render_reference(
+    reference: &Reference,
+    mut open: Signal<bool>,
+    mut target: Signal<Option<DetailTarget>>,
+) -> Element {
+    let database = DATABASE();
+    let names = TERM_NAMES.read();
+    let rendered = match reference.kind {
+        ReferenceKind::Skill => reference.resolve_skill(&database).map(|s| {
+            let t = DetailTarget::Skill(Signal::new(s.clone()));
+            rsx! {
+                a {
+                    class: "cursor-pointer",
+                    onclick: move |_| {
+                        *open.write() = true;
+                        *target.write() = Some(t.clone());
+                    },
+                    prevent_default: "onclick",
+                    SpriteIcon {
+                        class: "rounded-md align-middle",
+                        sprite: Signal::new(s.modes[0].icon.clone()),
+                        size: 20
+                    }
+                    "{s.name}"
+                }
+            }
+        }),
+        ReferenceKind::Rune => reference.resolve_rune(&database).map(|r| {
+            let t = DetailTarget::Rune(Signal::new(r.clone()));
+            rsx! {
+                a {
+                    class: "cursor-pointer",
+                    onclick: move |_| {
+                        *open.write() = true;
+                        *target.write() = Some(t.clone());
+                    },
+                    prevent_default: "onclick",
+                    SpriteIcon {
+                        class: "rounded-md align-middle",
+                        sprite: Signal::new(r.icon.clone()),
+                        size: 20
+                    }
+                    "{r.name}"
+                }
+            }
+        }),
+        ReferenceKind::State => reference.resolve_term(&names).map(|id| {
+            let name = database.term.get(&format!("NM-{}", id));
+            rsx! {
+                span { class: "inline-block border-b-2 border-primary border-dotted",
+                    Tooltip { name: id.to_string(),
+                        span { class: "text-primary", Description { tokens: name } }
+                    }
+                }
+            }
+        }),
+        ReferenceKind::Enemy => reference.resolve_term(&names).map(|id| {
+            let name = database.term.get(&format!("NM-{}", id));
+            rsx! {
+                Description { tokens: name }
+            }
+        }),
+    };
+
+    rendered.unwrap_or_else(|| {
+        rsx! {
+            span {
+                class: "text-error underline decoration-wavy",
+                title: "Unresolved reference",
+                "{reference}"
+            }
+        }
+    })
+}
+
+#[derive(Clone, PartialEq)]
+pub enum DetailTarget {
+    Skill(Signal<data::Skill>),
+    Rune(Signal<data::Rune>),
+}
+
+#[component]
+pub fn DetailDialog(
+    language: Language,
+    open: Signal<bool>,
+    target: Signal<Option<DetailTarget>>,
+) -> Element {
+    if let Some(t) = target.read().as_ref() {
+        rsx! {
+            Dialog {
+                open,
+                on_close: move |_| {
+                    *open.write() = false;
+                    *target.write() = None;
+                },
+                div { class: "mt-12",
+                    match t {
+                        DetailTarget::Skill(skill) => {
+                            rsx! {
+                                SkillView { language, skill: *skill }
+                            }
+                        }
+                        DetailTarget::Rune(rune) => {
+                            rsx! {
+                                RuneView { language, rune: *rune }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    } else {
+        None
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+use dioxus::router::router;
+use serde::{Deserialize, Serialize};
+
+use data::term::GlossaryEntry;
+use data::tokenizer::tokenize;
+
+use crate::global::{DATABASE, DATABASE_LOADING};
+use crate::pages::Route;
+use crate::ui::Description;
+use crate::Language;
+
+#[derive(Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct GlossaryState {
+    query: String,
+    /// The key of the entry to scroll to, as linked from its tooltip.
+    term: Option<String>,
+}
+
+impl GlossaryState {
+    pub fn term(key: &str) -> Self {
+        GlossaryState {
+            query: String::new(),
+            term: Some(key.to_string()),
+        }
+    }
+}
+
+impl FromStr for GlossaryState {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+impl Display for GlossaryState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_string(self) {
+            Ok(s) => f.write_str(&s),
+            Err(_) => Err(std::fmt::Error),
+        }
+    }
+}
+
+#[component]
+pub fn GlossaryPage(language: Language, state: GlossaryState) -> Element {
+    // Collecting the keywords walks every description, so not on each keystroke
+    let glossary = use_memo(|| DATABASE().glossary());
+    let total = glossary.read().len();
+    let entries = glossary
+        .read()
+        .iter()
+        .filter(|entry| entry.matches(&state.query))
+        .cloned()
+        .collect::<Vec<_>>();
+    let highlight = tokenize(&state.query);
+
+    rsx! {
+        div { class: "text-sm breadcrumbs",
+            ul {
+                li { "Home" }
+                li { "Glossary" }
+            }
+        }
+
+        div { class: "flex flex-row items-center gap-4",
+            div { class: "relative flex-grow",
+                input {
+                    class: "input input-bordered input-primary w-full",
+                    r#type: "text",
+                    placeholder: "Search keywords...",
+                    autofocus: state.term.is_none(),
+                    value: "{state.query}",
+                    oninput: move |e| {
+                        router()
+                            .replace(Route::GlossaryPage {
+                                language: language.clone(),
+                                state: GlossaryState {
+                                    query: e.data.value(),
+                                    term: None,
+                                },
+                            });
+                    }
+                }
+            }
+            div { class: "badge badge-accent badge-lg gap-1 text-xs",
+                span { class: "font-bold", "{entries.len()}" }
+                span { "of" }
+                span { class: "font-bold", "{total}" }
+            }
+        }
+
+        if total == 0 && DATABASE_LOADING() {
+            span { class: "loading loading-spinner mt-4" }
+        }
+
+        dl { class: "flex flex-col gap-2 mt-4",
+            for entry in entries {
+                GlossaryItem {
+                    key: "{entry.key}",
+                    target: state.term.clone(),
+                    entry,
+                    highlight: highlight.clone()
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn GlossaryItem(entry: GlossaryEntry, target: Option<String>, highlight: Vec<String>) -> Element {
+    let id = entry.anchor();
+    let target = target.is_some_and(|t| t == entry.key);
+    let border = if target {
+        "border-primary"
+    } else {
+        "border-base-300"
+    };
+    let scroll_id = id.clone();
+
+    rsx! {
+        div {
+            id: "{id}",
+            class: "card card-compact card-bordered bg-base-200 scroll-mt-20 {border}",
+            onmounted: move |_| {
+                if target {
+                    scroll_to(&scroll_id);
+                }
+            },
+            div { class: "card-body",
+                dt { class: "card-title text-base",
+                    Description { tokens: entry.name, highlight: highlight.clone() }
+                }
+                dd {
+                    Description { tokens: entry.description, highlight }
+                }
+            }
+        }
+    }
+}
+
+/// Scrolls to the entry linked from a tooltip once it is rendered.
+fn scroll_to(id: &str) {
+    if let Some(element) = gloo_utils::document().get_element_by_id(id) {
+        element.scroll_into_view();
+    }
+}
@@ -3,11 +3,12 @@ use dioxus::router::router;
 
 use crate::pages::skill::SkillListState;
 use crate::pages::Route;
+use crate::Language;
 
 #[component]
 pub fn Home() -> Element {
     router().replace(Route::SkillListPage {
-        language: "en".to_string().into(),
+        language: Language::default(),
         state: SkillListState::default(),
     });
 
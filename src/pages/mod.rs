@@ -1,26 +1,45 @@
 #![allow(non_snake_case)]
 
+use std::rc::Rc;
+
 use anyhow::anyhow;
 use auth0_spa::{
     use_auth0_context, AuthorizationParams, LogoutOptions, LogoutParams, RedirectLoginOptions,
 };
-use build::BuildEditPage;
-use data::{Database, LANGUAGES};
+use build::{BuildEditPage, BuildListPage, BuildPage};
+use build_format::TermNames;
+use classes::classes;
+use data::{
+    ArtifactError, Database, DatabaseChunk, DatabaseIndex, Manifest, SearchIndexes, LANGUAGES,
+};
 use dioxus::prelude::*;
+use futures_util::StreamExt;
+use glossary::GlossaryPage;
+pub use glossary::GlossaryState;
+use headlessui::{
+    Listbox, ListboxButton, ListboxOption, ListboxOptionRenderArgs, ListboxOptions, Menu,
+    MenuButton, MenuItem, MenuItemRenderArgs, MenuItems, RenderFn,
+};
 use home::Home;
 use rune::{RuneDebugPage, RuneListPage, RuneListState, RunePage};
 use skill::{SkillDebugPage, SkillListPage, SkillListState, SkillPage};
 
-use crate::global::{DATABASE, SEARCH_CATALOGS, THEME};
-use crate::search::{RuneSearch, SearchCatalogs, SkillSearch};
+use crate::components::CommandPalette;
+use crate::global::{
+    DATABASE, DATABASE_ERROR, DATABASE_INDEX, DATABASE_LOADING, DATABASE_PROGRESS, SEARCH_CATALOGS,
+    TERM_NAMES, THEME,
+};
+use crate::hooks::{precache_database, use_service_worker};
+use crate::search::SearchCatalogs;
 use crate::ui::Icon;
 use crate::Language;
 
 mod build;
+mod glossary;
 mod home;
-// mod planner;
+pub mod planner;
 mod rune;
-mod skill;
+pub mod skill;
 
 #[derive(Routable, Clone)]
 pub enum Route {
@@ -34,6 +53,13 @@ pub enum Route {
     #[layout(MainLayout)]
     #[route("/build")]
     BuildEditPage { language: Language },
+    #[route("/build/:build_id")]
+    BuildPage {
+        language: Language,
+        build_id: String,
+    },
+    #[route("/builds")]
+    BuildListPage { language: Language },
 
     // #[route("/planner?:state")]
     // PlannerPage { state: PlannerState },
@@ -61,6 +87,12 @@ pub enum Route {
     RuneDebugPage { language: Language },
     #[route("/rune/:rune_id")]
     RunePage { language: Language, rune_id: String },
+
+    #[route("/glossary?:state")]
+    GlossaryPage {
+        language: Language,
+        state: GlossaryState,
+    },
     #[end_layout]
     #[end_nest]
     #[route("/:..route")]
@@ -79,6 +111,12 @@ impl SetLanguage for Route {
             Route::BuildEditPage {
                 ref mut language, ..
             } => *language = lang,
+            Route::BuildPage {
+                ref mut language, ..
+            } => *language = lang,
+            Route::BuildListPage {
+                ref mut language, ..
+            } => *language = lang,
             Route::SkillListPage {
                 ref mut language, ..
             } => *language = lang,
@@ -97,11 +135,33 @@ impl SetLanguage for Route {
             Route::RunePage {
                 ref mut language, ..
             } => *language = lang,
+            Route::GlossaryPage {
+                ref mut language, ..
+            } => *language = lang,
             Route::PageNotFound { .. } => (),
         }
     }
 }
 
+impl Route {
+    /// The language of the pages under `MainLayout`.
+    pub fn language(&self) -> Option<Language> {
+        match self {
+            Route::Home { .. } | Route::AuthCallback { .. } | Route::PageNotFound { .. } => None,
+            Route::BuildEditPage { language, .. }
+            | Route::BuildPage { language, .. }
+            | Route::BuildListPage { language, .. }
+            | Route::SkillListPage { language, .. }
+            | Route::SkillDebugPage { language, .. }
+            | Route::SkillPage { language, .. }
+            | Route::RuneListPage { language, .. }
+            | Route::RuneDebugPage { language, .. }
+            | Route::RunePage { language, .. }
+            | Route::GlossaryPage { language, .. } => Some(language.clone()),
+        }
+    }
+}
+
 #[component]
 fn PageNotFound(route: Vec<String>) -> Element {
     rsx! {
@@ -111,26 +171,89 @@ fn PageNotFound(route: Vec<String>) -> Element {
     }
 }
 
-async fn fetch_database(lang: &str) -> anyhow::Result<Database> {
+fn i18n_url(lang: &str, name: &str) -> anyhow::Result<String> {
+    let base_uri = gloo_utils::document()
+        .base_uri()
+        .map_err(|err| anyhow!(format!("{:?}", err)))?;
+    let base_uri = base_uri.ok_or(anyhow!("base_uri"))?;
+    Ok(format!("{}i18n/{}/{}", base_uri, lang, name))
+}
+
+/// Downloads a file of the database, passing the fraction received so far to `progress`.
+async fn fetch_i18n(lang: &str, name: &str, progress: impl Fn(f64)) -> anyhow::Result<Vec<u8>> {
+    let res = reqwest::get(i18n_url(lang, name)?)
+        .await?
+        .error_for_status()?;
+    let length = res.content_length();
+    let mut body = Vec::with_capacity(length.unwrap_or(0) as usize);
+    let mut stream = res.bytes_stream();
+    while let Some(bytes) = stream.next().await {
+        body.extend_from_slice(&bytes?);
+        if let Some(length) = length {
+            progress((body.len() as f64 / length as f64).min(1.0));
+        }
+    }
+    Ok(body)
+}
+
+/// Reports the progress of the `step`th of the `steps` files of the database to
+/// `DATABASE_PROGRESS`.
+fn step_progress(step: usize, steps: usize) -> impl Fn(f64) {
+    move |part| *DATABASE_PROGRESS.write() = (step as f64 + part) / steps as f64
+}
+
+/// The files fetched after the manifest: the index, the chunks and the search indexes.
+fn step_count(manifest: &Manifest) -> usize {
+    manifest.chunks.len() + 2
+}
+
+/// The manifest and the index of the database, enough to show the lists.
+async fn fetch_index(lang: &str) -> anyhow::Result<(Manifest, DatabaseIndex)> {
     if let Some(_) = LANGUAGES.iter().find(|l| *l == &lang) {
-        let base_uri = gloo_utils::document()
-            .base_uri()
-            .map_err(|err| anyhow!(format!("{:?}", err)))?;
-        let base_uri = base_uri.ok_or(anyhow!("base_uri"))?;
-
-        let database = {
-            let res = reqwest::get(format!("{}i18n/{}/database.msgpack", base_uri, lang)).await?;
-            let body = res.bytes().await?;
-            let cursor = std::io::Cursor::new(body);
-            Database::read(cursor)?
-        };
-
-        Ok(database)
+        let manifest = Manifest::read(&fetch_i18n(lang, "manifest.msgpack", |_| ()).await?[..])?;
+        let progress = step_progress(0, step_count(&manifest));
+        let index = DatabaseIndex::read(&fetch_i18n(lang, &manifest.index, progress).await?[..])?;
+        Ok((manifest, index))
     } else {
         Err(anyhow!("unknown language: {}", lang))
     }
 }
 
+/// Adds the chunks to `DATABASE` as they arrive, then builds the search catalogs. Stops
+/// when the language has changed meanwhile.
+async fn load_chunks(
+    lang: Signal<Language>,
+    language: Language,
+    manifest: Manifest,
+    index: DatabaseIndex,
+) -> anyhow::Result<()> {
+    let steps = step_count(&manifest);
+    for (i, entry) in manifest.chunks.iter().enumerate() {
+        let progress = step_progress(i + 1, steps);
+        let chunk = DatabaseChunk::read(&fetch_i18n(&language, &entry.file, progress).await?[..])?;
+        if *lang.peek() != language {
+            return Ok(());
+        }
+        DATABASE.write().insert_chunk(&index, chunk);
+        *TERM_NAMES.write() = TermNames::new(&DATABASE.read());
+    }
+    let progress = step_progress(steps - 1, steps);
+    let indexes =
+        SearchIndexes::read(&fetch_i18n(&language, &manifest.search, progress).await?[..])?;
+    if *lang.peek() != language {
+        return Ok(());
+    }
+    *SEARCH_CATALOGS.write() = SearchCatalogs::new(&DATABASE.read(), indexes);
+
+    let files = std::iter::once(&manifest.index)
+        .chain(std::iter::once(&manifest.search))
+        .chain(manifest.chunks.iter().map(|c| &c.file))
+        .map(|file| i18n_url(&language, file))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    precache_database(&language, &manifest.version, &files);
+    Ok(())
+}
+
 #[component]
 fn MainLayout(language: Language) -> Element {
     let l = language.clone();
@@ -138,33 +261,56 @@ fn MainLayout(language: Language) -> Element {
     if lang() != language {
         *lang.write() = language.clone();
     }
-    let database_future = use_resource(move || async move {
-        tracing::info!("loading database: {:?}", lang);
-        let db = fetch_database(&lang()).await;
-        match db {
-            Ok(v) => {
-                let skill = &v.skill;
-                let rune = &v.rune;
-                let catalogs = SearchCatalogs {
-                    skill: crate::search::create_catalog::<SkillSearch, SkillSearch, SkillSearch>(
-                        skill.clone(),
-                        lang().clone().into(),
-                    ),
-                    rune: crate::search::create_catalog::<RuneSearch, RuneSearch, RuneSearch>(
-                        rune.clone(),
-                        lang().clone().into(),
-                    ),
-                };
-
-                *SEARCH_CATALOGS.write() = catalogs;
-                *DATABASE.write() = v;
-                Some(Ok(()))
+    let mut data_updated = use_service_worker();
+    let mut index_loading = use_signal(|| true);
+    let mut database_future = use_resource(move || async move {
+        let language = lang();
+        if !language.is_supported() {
+            return;
+        }
+        tracing::info!("loading database: {:?}", language);
+        *DATABASE_ERROR.write() = None;
+        *DATABASE_PROGRESS.write() = 0.0;
+        index_loading.set(true);
+        let result = fetch_index(&language).await;
+        if *lang.peek() != language {
+            return;
+        }
+        index_loading.set(false);
+        match result {
+            Ok((manifest, index)) => {
+                *DATABASE.write() = Database::from_index(&index);
+                *TERM_NAMES.write() = TermNames::default();
+                *DATABASE_INDEX.write() = index.clone();
+                *SEARCH_CATALOGS.write() = SearchCatalogs::default();
+                *DATABASE_LOADING.write() = true;
+                spawn(async move {
+                    let result = load_chunks(lang, language.clone(), manifest, index).await;
+                    if *lang.peek() == language {
+                        if let Err(e) = result {
+                            tracing::error!("failed to load database chunks: {:#}", e);
+                            *DATABASE_ERROR.write() = Some(Rc::new(e));
+                        }
+                        *DATABASE_LOADING.write() = false;
+                    }
+                });
+            }
+            Err(e) => {
+                tracing::error!("failed to load database: {:#}", e);
+                *DATABASE_ERROR.write() = Some(Rc::new(e));
             }
-            Err(e) => Some(Err(e)),
         }
     });
 
-    let main = rsx! {
+    if !language.is_supported() {
+        tracing::warn!("unsupported language: {:?}", language);
+        let mut route = router().current::<Route>();
+        route.set_language(Language::default());
+        router().replace(route);
+        return None;
+    }
+
+    rsx! {
         div { class: "drawer",
             input {
                 class: "drawer-toggle",
@@ -197,13 +343,47 @@ fn MainLayout(language: Language) -> Element {
                             }
                         }
                         div { class: "navbar-end pr-4",
+                            CommandPalette { language: lang() }
                             ThemeSelect {}
                             LanguageSelect {}
                             Auth {}
                         }
                     }
                 }
-                main { class: "container my-4 mx-auto", Outlet::<Route> {} }
+                if data_updated().is_some_and(|l| l == *lang()) {
+                    div { role: "alert", class: "alert alert-info rounded-none",
+                        span { "New data is available." }
+                        button {
+                            class: "btn btn-sm",
+                            onclick: move |_| {
+                                *data_updated.write() = None;
+                                web_sys::window().unwrap().location().reload().unwrap();
+                            },
+                            "Reload"
+                        }
+                    }
+                }
+                if DATABASE_LOADING() {
+                    progress {
+                        class: "progress progress-primary block w-full h-1 rounded-none",
+                        value: "{DATABASE_PROGRESS() * 100.0}",
+                        max: "100"
+                    }
+                }
+                main { class: "container my-4 mx-auto",
+                    if let Some(err) = DATABASE_ERROR() {
+                        DatabaseError {
+                            message: database_error_message(&err),
+                            detail: format!("{:#}", err),
+                            retryable: is_retryable(&err),
+                            on_retry: move |_| database_future.restart()
+                        }
+                    } else if index_loading() {
+                        DatabaseSkeleton {}
+                    } else {
+                        Outlet::<Route> {}
+                    }
+                }
                 footer { class: "footer p-8 bg-neutral text-neutral-content",
                     div {
                         class: "flex flex-row items-center w-full",
@@ -239,17 +419,78 @@ fn MainLayout(language: Language) -> Element {
                 }
             }
         }
-    };
-
-    match database_future.value().as_ref() {
-        None => None,
-        Some(v) => match *v {
-            None => None,
-            Some(Ok(_)) => main,
-            Some(Err(ref err)) => {
-                rsx! {"An error occurred while fetching database: {err}"}
+    }
+}
+
+/// What went wrong, for the reader.
+fn database_error_message(err: &anyhow::Error) -> String {
+    match err.downcast_ref::<ArtifactError>() {
+        Some(ArtifactError::UnsupportedVersion(_)) => {
+            "The data has been updated for a newer version of this site. Reload the page to get it."
+        }
+        Some(ArtifactError::Corrupt(_)) | Some(ArtifactError::Truncated) => {
+            "The data could not be read, it may have been damaged while downloading."
+        }
+        None => "The data could not be downloaded. Check your connection and try again.",
+    }
+    .to_string()
+}
+
+/// Whether fetching the database again may help. A newer format needs the newer site.
+fn is_retryable(err: &anyhow::Error) -> bool {
+    !matches!(
+        err.downcast_ref::<ArtifactError>(),
+        Some(ArtifactError::UnsupportedVersion(_))
+    )
+}
+
+#[component]
+fn DatabaseError(
+    message: String,
+    detail: String,
+    retryable: bool,
+    on_retry: EventHandler<()>,
+) -> Element {
+    rsx! {
+        div { role: "alert", class: "alert alert-error",
+            div {
+                p { "{message}" }
+                details { class: "text-xs opacity-70",
+                    summary { "Details" }
+                    "{detail}"
+                }
             }
-        },
+            if retryable {
+                button { class: "btn btn-sm", onclick: move |_| on_retry.call(()), "Retry" }
+            } else {
+                button {
+                    class: "btn btn-sm",
+                    onclick: move |_| web_sys::window().unwrap().location().reload().unwrap(),
+                    "Reload"
+                }
+            }
+        }
+    }
+}
+
+/// Stands in for the page while the index of the database is downloaded.
+#[component]
+fn DatabaseSkeleton() -> Element {
+    rsx! {
+        div { class: "flex flex-col gap-4", aria_busy: "true",
+            div { class: "skeleton h-8 w-48" }
+            div { class: "skeleton h-12 w-full" }
+            div { class: "flex flex-wrap gap-2",
+                for i in 0..24 {
+                    div { key: "{i}", class: "skeleton w-20 h-20" }
+                }
+            }
+            progress {
+                class: "progress w-56 mx-auto",
+                value: "{DATABASE_PROGRESS() * 100.0}",
+                max: "100"
+            }
+        }
     }
 }
 
@@ -309,6 +550,9 @@ fn NavMenu(language: Language) -> Element {
         li {
             Link { to: Route::BuildEditPage { language: language.clone() }, "Build" }
         }
+        li {
+            Link { to: Route::BuildListPage { language: language.clone() }, "My builds" }
+        }
         li {
             Link {
                 to: Route::SkillListPage {
@@ -327,22 +571,39 @@ fn NavMenu(language: Language) -> Element {
                 "Rune"
             }
         }
+        li {
+            Link {
+                to: Route::GlossaryPage {
+                    language: language.clone(),
+                    state: GlossaryState::default(),
+                },
+                "Glossary"
+            }
+        }
     }
 }
 
 #[component]
 fn LanguageSelect() -> Element {
     let route = router().current::<Route>();
+    let render_item = RenderFn::<MenuItemRenderArgs>::new(|args| {
+        let class = classes!["rounded-btn", "bg-base-content/10" => args.active];
+        rsx! {
+            li { class, ..args.attrs, {args.children} }
+        }
+    });
     rsx! {
-        div { class: "dropdown dropdown-end",
-            div { class: "btn btn-ghost btn-sm rounded-btn", role: "button", tabindex: 0,
+        Menu { class: "relative",
+            MenuButton { class: "btn btn-ghost btn-sm rounded-btn", aria_label: "Language",
                 Icon { svg: r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" class="w-6 h-6"><path stroke-linecap="round" stroke-linejoin="round" d="m10.5 21 5.25-11.25L21 21m-9-3h7.5M3 5.621a48.474 48.474 0 0 1 6-.371m0 0c1.12 0 2.233.038 3.334.114M9 5.25V3m3.334 2.364C11.176 10.658 7.69 15.08 3 17.502m9.334-12.138c.896.061 1.785.147 2.666.257m-4.589 8.495a18.023 18.023 0 0 1-3.827-5.802" /></svg>"# }
             }
-            ul {
-                class: "p-2 shadow menu dropdown-content bg-base-100 text-base-content rounded-box z-10 max-h-fit overflow-y-auto w-48",
-                tabindex: 0,
-                li {
-                    for t in LANGUAGES.iter() {
+            MenuItems {
+                class: "absolute right-0 mt-2 p-2 shadow menu bg-base-100 text-base-content rounded-box z-10 max-h-fit overflow-y-auto w-48 outline-none",
+                for (index, t) in LANGUAGES.iter().enumerate() {
+                    MenuItem {
+                        key: "{t}",
+                        index,
+                        render: Some(render_item.clone()),
                         Link {
                             to: {
                                 let mut r = route.clone();
@@ -392,25 +653,29 @@ const THEMES: [&str; 29] = [
 
 #[component]
 fn ThemeSelect() -> Element {
+    let value = THEMES.iter().position(|t| *t == THEME()).unwrap_or(0);
+    let render_option = RenderFn::<ListboxOptionRenderArgs>::new(|args| {
+        let class = classes![
+            "btn btn-ghost btn-sm justify-start px-4 py-2",
+            "btn-active" => args.active,
+            "text-primary" => args.selected
+        ];
+        rsx! {
+            li { class, ..args.attrs, {args.children} }
+        }
+    });
     rsx! {
-        div { class: "dropdown dropdown-end",
-            div {
-                class: "btn btn-ghost btn-sm rounded-btn",
-                tabindex: 0,
-                role: "button",
+        Listbox {
+            class: "relative",
+            value,
+            on_change: move |index: usize| *THEME.write() = THEMES[index].to_string(),
+            ListboxButton { class: "btn btn-ghost btn-sm rounded-btn", aria_label: "Theme",
                 Icon { svg: r#"<svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="1.5" stroke="currentColor" class="w-6 h-6"><path stroke-linecap="round" stroke-linejoin="round" d="M4.098 19.902a3.75 3.75 0 0 0 5.304 0l6.401-6.402M6.75 21A3.75 3.75 0 0 1 3 17.25V4.125C3 3.504 3.504 3 4.125 3h5.25c.621 0 1.125.504 1.125 1.125v4.072M6.75 21a3.75 3.75 0 0 0 3.75-3.75V8.197M6.75 21h13.125c.621 0 1.125-.504 1.125-1.125v-5.25c0-.621-.504-1.125-1.125-1.125h-4.072M10.5 8.197l2.88-2.88c.438-.439 1.15-.439 1.59 0l3.712 3.713c.44.44.44 1.152 0 1.59l-2.879 2.88M6.75 17.25h.008v.008H6.75v-.008Z" /></svg>"# }
             }
-            div {
-                class: "p-2 shadow menu dropdown-content bg-base-100 text-base-content rounded-box z-10 max-h-96 overflow-y-auto w-48",
-                tabindex: 0,
-                div { class: "grid grid-cols-1 gap-2 p-4",
-                    for t in THEMES.iter() {
-                        button {
-                            class: "btn btn-ghost btn-sm justify-start px-4 py-2",
-                            onclick: move |_| *THEME.write() = t.to_string(),
-                            "{t}"
-                        }
-                    }
+            ListboxOptions {
+                class: "absolute right-0 mt-2 p-4 shadow grid grid-cols-1 gap-2 bg-base-100 text-base-content rounded-box z-10 max-h-96 overflow-y-auto w-48 outline-none",
+                for (index, t) in THEMES.iter().enumerate() {
+                    ListboxOption { key: "{t}", index, render: Some(render_option.clone()), "{t}" }
                 }
             }
         }
@@ -1,13 +1,9 @@
 use dioxus::prelude::*;
 use dioxus::router::router;
-use dioxus_signals::Signal;
 
-use crate::components::SkillView;
-use crate::global::DATABASE;
-use crate::pages::planner::{PlannerState, SlotState};
+use crate::pages::planner::{PlannerSlot, PlannerState};
 use crate::pages::skill::SkillList;
 use crate::pages::Route;
-use crate::ui::SpriteIcon;
 
 #[component]
 pub fn PlannerPage(state: PlannerState) -> Element {
@@ -53,42 +49,3 @@ pub fn PlannerEditSlotPage(state: PlannerState, index: i32) -> Element {
         }
     }
 }
-
-#[component]
-fn PlannerSlot(index: i32, state: SlotState, on_click: EventHandler<()>) -> Element {
-    let maybe_skill = state
-        .skill
-        .and_then(|hash| DATABASE().skill.get(&hash).map(|s| Signal::new(s.clone())));
-
-    rsx! {
-        if let Some(skill) = maybe_skill {
-            div { class: "collapse collapse-arrow", tabindex: "{index}",
-                div { class: "collapse-title px-2 py-1",
-                    span { class: "badge badge-neutral mr-2", "{index + 1}" }
-                    span {
-                        class: "hover:bg-primary border-primary border-solid border-2 rounded-md p-1 inline-block",
-                        onclick: move |_| on_click.call(()),
-                        span { class: "relative",
-                            SpriteIcon {
-                                class: "rounded-md",
-                                sprite: Signal::new(skill().modes[0].icon.clone()),
-                                size: 48
-                            }
-                        }
-                    }
-                    span { class: "ml-2", "{skill().name}" }
-                }
-                div { class: "collapse-content", SkillView { skill: skill } }
-            }
-        } else {
-            div { class: "px-2 py-1",
-                span { class: "badge badge-neutral mr-2", "{index + 1}" }
-                button {
-                    class: "ml-2 btn btn-primary btn-wide",
-                    onclick: move |_| on_click.call(()),
-                    "Click to select skill"
-                }
-            }
-        }
-    }
-}
@@ -8,9 +8,10 @@ use serde::{Deserialize, Serialize};
 
 use data::{RuneHash, SkillHash};
 
-mod edit;
+// mod edit;
+mod slot;
 
-pub use edit::*;
+pub use slot::*;
 
 #[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PlannerState {
@@ -23,11 +24,17 @@ struct BuildState {
 }
 
 #[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
-struct SlotState {
+pub struct SlotState {
     skill: Option<SkillHash>,
     runes: [RuneHash; 5],
 }
 
+impl PlannerState {
+    pub fn slots(&self) -> &[SlotState] {
+        &self.build.slots
+    }
+}
+
 impl FromStr for PlannerState {
     type Err = rmp_serde::decode::Error;
 
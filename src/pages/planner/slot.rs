@@ -0,0 +1,62 @@
+use dioxus::prelude::*;
+
+use crate::components::SkillView;
+use crate::global::DATABASE;
+use crate::pages::planner::SlotState;
+use crate::ui::SpriteIcon;
+use crate::Language;
+
+/// A planner slot. Without `on_click` the slot is read-only, as in build documents.
+#[component]
+pub fn PlannerSlot(
+    language: Language,
+    index: i32,
+    state: SlotState,
+    on_click: Option<EventHandler<()>>,
+) -> Element {
+    let maybe_skill = state
+        .skill
+        .and_then(|hash| DATABASE().skill.get(&hash).map(|s| Signal::new(s.clone())));
+
+    rsx! {
+        if let Some(skill) = maybe_skill {
+            div { class: "collapse collapse-arrow", tabindex: "{index}",
+                div { class: "collapse-title px-2 py-1",
+                    span { class: "badge badge-neutral mr-2", "{index + 1}" }
+                    span {
+                        class: "hover:bg-primary border-primary border-solid border-2 rounded-md p-1 inline-block",
+                        onclick: move |_| {
+                            if let Some(on_click) = on_click {
+                                on_click.call(());
+                            }
+                        },
+                        span { class: "relative",
+                            SpriteIcon {
+                                class: "rounded-md",
+                                sprite: Signal::new(skill().modes[0].icon.clone()),
+                                size: 48
+                            }
+                        }
+                    }
+                    span { class: "ml-2", "{skill().name}" }
+                }
+                div { class: "collapse-content",
+                    SkillView { language, skill }
+                }
+            }
+        } else {
+            div { class: "px-2 py-1",
+                span { class: "badge badge-neutral mr-2", "{index + 1}" }
+                if let Some(on_click) = on_click {
+                    button {
+                        class: "ml-2 btn btn-primary btn-wide",
+                        onclick: move |_| on_click.call(()),
+                        "Click to select skill"
+                    }
+                } else {
+                    span { class: "ml-2 opacity-50", "Empty slot" }
+                }
+            }
+        }
+    }
+}
@@ -6,7 +6,7 @@ use dioxus::router::router;
 use serde::{Deserialize, Serialize};
 
 use crate::components::RuneView;
-use crate::global::DATABASE;
+use crate::global::DATABASE_INDEX;
 use crate::hooks::use_search_rune;
 use crate::pages::Route;
 use crate::Language;
@@ -54,6 +54,7 @@ pub fn RuneListPage(language: Language, state: RuneListState) -> Element {
                     class: "input input-bordered input-primary w-full",
                     r#type: "text",
                     placeholder: "Search runes...",
+                    title: "name:, desc:, rarity>=4, -exclude, \"exact phrase\"",
                     autofocus: true,
                     value: "{state.query}",
                     oninput: move |e| {
@@ -72,13 +73,17 @@ pub fn RuneListPage(language: Language, state: RuneListState) -> Element {
             div { class: "badge badge-accent badge-lg gap-1 text-xs",
                 span { class: "font-bold", "{search.results.read().len()}" }
                 span { "of" }
-                span { class: "font-bold", "{DATABASE().rune.iter().count()}" }
+                span { class: "font-bold", "{DATABASE_INDEX.read().runes.len()}" }
             }
         }
 
         div { class: "flex flex-wrap gap-2 mt-4",
             for rune in search.results.read().iter() {
-                div { class: "flex-1 min-w-64", RuneView { language: language.clone(), rune: rune.clone() } }
+                div { class: "flex-1 min-w-64", RuneView {
+                        language: language.clone(),
+                        rune: rune.clone(),
+                        highlight: search.terms.read().clone()
+                    } }
             }
         }
     }
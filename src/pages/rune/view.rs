@@ -1,7 +1,7 @@
 use crate::components::RuneView;
 use dioxus::prelude::*;
 
-use crate::global::DATABASE;
+use crate::global::{DATABASE, DATABASE_LOADING};
 use crate::Language;
 
 #[component]
@@ -24,6 +24,10 @@ pub fn RunePage(language: Language, rune_id: String) -> Element {
             }
         })
         .unwrap_or_else(|| {
-            rsx! { div { "Rune not found" } }
+            if DATABASE_LOADING() {
+                rsx! { span { class: "loading loading-spinner" } }
+            } else {
+                rsx! { div { "Rune not found" } }
+            }
         })
 }
@@ -7,9 +7,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::components::SkillView;
 use data::skill::Skill;
-use data::SkillHash;
+use data::{SkillHash, SkillSummary};
 
-use crate::global::DATABASE;
+use crate::global::{DATABASE, DATABASE_INDEX, DATABASE_LOADING};
 use crate::hooks::use_search_skill;
 use crate::pages::Route;
 use crate::ui::{Dialog, SpriteIcon};
@@ -20,6 +20,14 @@ pub struct SkillListState {
     query: Signal<String>,
 }
 
+impl SkillListState {
+    pub fn new(query: String) -> Self {
+        Self {
+            query: Signal::new(query),
+        }
+    }
+}
+
 impl FromStr for SkillListState {
     type Err = serde_json::Error;
 
@@ -86,6 +94,7 @@ pub fn SkillList(
                         class: "input input-bordered input-primary w-full",
                         r#type: "text",
                         placeholder: "Search skills...",
+                        title: "name:, desc:, category:attack, trigger:passive, rarity>=4, cd<=3, -exclude, \"exact phrase\"",
                         autofocus: true,
                         value: "{query}",
                         oninput: move |e| {
@@ -98,7 +107,7 @@ pub fn SkillList(
                 div { class: "badge badge-accent badge-lg gap-1 text-xs",
                     span { class: "font-bold", "{search.results.read().len()}" }
                     span { "of" }
-                    span { class: "font-bold", "{DATABASE().skill.iter().count()}" }
+                    span { class: "font-bold", "{DATABASE_INDEX.read().skills.len()}" }
                 }
             }
 
@@ -112,6 +121,16 @@ pub fn SkillList(
                         }
                     }
                 }
+                if DATABASE_LOADING() && search.query.read().is_empty() {
+                    for summary in DATABASE_INDEX
+                        .read()
+                        .skills
+                        .iter()
+                        .filter(|s| !DATABASE.read().skill.contains_key(&s.hash))
+                    {
+                        SkillPlaceholder { summary: summary.clone() }
+                    }
+                }
             }
         }
 
@@ -120,6 +139,7 @@ pub fn SkillList(
             open: detail_open,
             maybe_skill: detail_skill,
             selectable: on_select.is_some(),
+            highlight: search.terms.read().clone(),
             on_select: move |e| on_select.clone().map_or((), |h| h.call(e))
         }
     }
@@ -131,6 +151,7 @@ pub fn DetailDialog(
     open: Signal<bool>,
     maybe_skill: Signal<Option<Signal<Skill>>>,
     selectable: bool,
+    #[props(default)] highlight: Vec<String>,
     on_select: EventHandler<SkillHash>,
 ) -> Element {
     if let Some(skill) = maybe_skill() {
@@ -154,7 +175,7 @@ pub fn DetailDialog(
                         }
                     }
                 }
-                div { class: "mt-12", SkillView { language, skill } }
+                div { class: "mt-12", SkillView { language, skill, highlight } }
             }
         }
     } else {
@@ -205,3 +226,26 @@ fn SkillLink(skill: Signal<Skill>, on_click: EventHandler<Signal<Skill>>) -> Ele
         }
     }
 }
+
+/// A skill of the index whose chunk is still loading.
+#[component]
+fn SkillPlaceholder(summary: SkillSummary) -> Element {
+    #[component]
+    fn SkillPlaceholderInnerIcon(summary: SkillSummary, class: &'static str, size: i32) -> Element {
+        rsx! {
+            span { class: "border-base-300 border-solid border-2 rounded-md p-1 text-[0] animate-pulse {class}",
+                span { class: "relative inline-block",
+                    SpriteIcon { class: "rounded-md", sprite: Signal::new(summary.icon.clone()), size }
+                    span { class: "absolute right-0 bg-black/50 text-white text-xs px-1 text-right",
+                        "{summary.name}"
+                    }
+                }
+            }
+        }
+    }
+
+    rsx! {
+        SkillPlaceholderInnerIcon { summary: summary.clone(), class: "inline-block md:hidden", size: 56 }
+        SkillPlaceholderInnerIcon { summary, class: "hidden md:inline-block", size: 96 }
+    }
+}
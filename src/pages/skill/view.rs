@@ -1,7 +1,7 @@
 use dioxus::prelude::*;
 
 use crate::components::SkillView;
-use crate::global::DATABASE;
+use crate::global::{DATABASE, DATABASE_LOADING};
 use crate::Language;
 
 #[component]
@@ -24,6 +24,10 @@ pub fn SkillPage(language: Language, skill_id: String) -> Element {
             }
         })
         .unwrap_or_else(|| {
-            rsx! { div { "Skill not found" } }
+            if DATABASE_LOADING() {
+                rsx! { span { class: "loading loading-spinner" } }
+            } else {
+                rsx! { div { "Skill not found" } }
+            }
         })
 }
@@ -1,19 +1,13 @@
-use std::collections::hash_map::DefaultHasher;
-use std::hash::Hash;
 use std::rc::Rc;
 
-use indicium::simple::{Indexable, KString, SearchIndex, SearchIndexBuilder, Tokenizer};
 use ref_cast::RefCast;
-use wasm_bindgen::prelude::*;
 
 use data::skill::{Skill, SkillHash, SkillRepository};
 use data::{
-    Repository, Rune, RuneHash, RuneRepository, Search, SearchIndexable, SearchMarker,
-    ToSearchMaker, LANGUAGES,
+    Database, Query, Repository, Rune, RuneHash, RuneRepository, Search, SearchIndex,
+    SearchIndexes, SearchMarker, ToSearchMaker,
 };
 
-use std::hash::Hasher;
-
 pub struct SearchCatalog<M: SearchMarker, T: Search<M>, R: Repository<T::Key, T::Item> + Default> {
     pub index: SearchIndex<T::Key>,
     pub repository: Rc<R>,
@@ -23,21 +17,23 @@ impl<M: SearchMarker, T: Search<M>, R: Repository<T::Key, T::Item> + Default> Pa
     for SearchCatalog<M, T, R>
 {
     fn eq(&self, other: &Self) -> bool {
-        let mut hasher_self = DefaultHasher::new();
-        self.index.hash(&mut hasher_self);
-
-        let mut hasher_other = DefaultHasher::new();
-        other.index.hash(&mut hasher_other);
-
-        hasher_self.finish() == hasher_other.finish()
+        self.index == other.index
     }
 }
 
 impl<M: SearchMarker, T: Search<M>, R: Repository<T::Key, T::Item> + Default>
     SearchCatalog<M, T, R>
 {
+    /// Evaluates a [`Query`] against the index and the items, best match first.
+    /// Items with equal scores keep the repository order.
     pub fn search<'a>(&'a self, query: &'a str) -> Vec<&'a T::Key> {
-        self.index.search(query)
+        let repository = self.repository.as_ref();
+        Query::parse(query).evaluate(
+            &self.index,
+            repository
+                .iter()
+                .filter_map(|key| repository.get(key).map(|item| (key, item))),
+        )
     }
 }
 
@@ -71,12 +67,6 @@ impl ToSearchMaker<SkillSearch, SkillSearch> for SkillSearch {
     }
 }
 
-impl<'a> Indexable for SkillSearch {
-    fn strings(&self) -> Vec<String> {
-        <Skill as SearchIndexable<SkillHash, SkillSearch, SkillSearch>>::strings(&self.0)
-    }
-}
-
 #[derive(RefCast)]
 #[repr(transparent)]
 pub struct RuneSearch(Rune);
@@ -96,74 +86,79 @@ impl ToSearchMaker<RuneSearch, RuneSearch> for RuneSearch {
     }
 }
 
-impl<'a> Indexable for RuneSearch {
-    fn strings(&self) -> Vec<String> {
-        <Rune as SearchIndexable<RuneHash, RuneSearch, RuneSearch>>::strings(&self.0)
-    }
-}
-
 #[derive(Default, PartialEq)]
 pub struct SearchCatalogs {
     pub skill: SearchCatalog<SkillSearch, SkillSearch, SkillRepository>,
     pub rune: SearchCatalog<RuneSearch, RuneSearch, RuneRepository>,
+    /// Keyed by the id of the `NM-{id}` term.
+    pub state: SearchIndex<String>,
+    /// Keyed by the id of the `NM-{id}` term.
+    pub enemy: SearchIndex<String>,
+    /// Other `NM-{id}` terms, keyed by the id.
+    pub term: SearchIndex<String>,
 }
 
-#[wasm_bindgen(module = "/src/tokenizer.js")]
-extern "C" {
-    type IntlTokenizer;
-
-    #[wasm_bindgen(constructor)]
-    fn new(locale: String) -> IntlTokenizer;
-
-    #[wasm_bindgen(method)]
-    fn tokenize(this: &IntlTokenizer, string: String) -> Vec<String>;
+/// A result of [`SearchCatalogs::search_all`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum GlobalSearchItem {
+    Skill(SkillHash),
+    Rune(RuneHash),
+    /// The id of the `NM-{id}` term.
+    State(String),
+    Enemy(String),
+    Term(String),
 }
 
-struct Document {
-    pub skill: Skill,
-}
+impl SearchCatalogs {
+    /// Pairs the indexes generated by `gen` with the repositories of `database`.
+    pub fn new(database: &Database, indexes: SearchIndexes) -> Self {
+        Self {
+            skill: SearchCatalog {
+                index: indexes.skill,
+                repository: database.skill.clone(),
+            },
+            rune: SearchCatalog {
+                index: indexes.rune,
+                repository: database.rune.clone(),
+            },
+            state: indexes.state,
+            enemy: indexes.enemy,
+            term: indexes.term,
+        }
+    }
 
-impl Indexable for Document {
-    fn strings(&self) -> Vec<String> {
-        let mut strings = vec![self.skill.name.clone()];
-        strings.push(
+    /// Searches every catalog at once, keeping the best `limit` results of each kind,
+    /// grouped by kind.
+    pub fn search_all(&self, query: &str, limit: usize) -> Vec<GlobalSearchItem> {
+        let mut items = vec![];
+        items.extend(
             self.skill
-                .modes
-                .iter()
-                .flat_map(|mode| vec![mode.name.clone(), format!("{}", mode.format())])
-                .collect(),
+                .search(query)
+                .into_iter()
+                .take(limit)
+                .map(|hash| GlobalSearchItem::Skill(*hash)),
         );
-        strings
-    }
-}
-
-pub fn create_catalog<'a, M: SearchMarker + Indexable, T: Search<M>, N: ToSearchMaker<M, T>>(
-    repository: Rc<T::Repository>,
-    language: String,
-) -> SearchCatalog<M, T, T::Repository>
-where
-    <T as Search<M>>::Repository: Default,
-{
-    let lang = LANGUAGES.iter().find(|l| *l == &language).unwrap();
-
-    let tokenizer: Tokenizer = Rc::new(Box::new(|string| {
-        let intl = IntlTokenizer::new(lang.to_string());
-        intl.tokenize(string.to_string())
-            .into_iter()
-            .map(|s| KString::from_string(s))
-            .collect::<Vec<_>>()
-    }));
-
-    let mut index = SearchIndexBuilder::default()
-        .tokenizer(Some(tokenizer))
-        .build();
-    for id in repository.iter() {
-        let item = repository.get(id).unwrap();
-        index.insert(id, N::to_search_marker(item));
-    }
-
-    SearchCatalog {
-        index,
-        repository: repository,
+        items.extend(
+            self.rune
+                .search(query)
+                .into_iter()
+                .take(limit)
+                .map(|hash| GlobalSearchItem::Rune(*hash)),
+        );
+        let terms: [(&SearchIndex<String>, fn(String) -> GlobalSearchItem); 3] = [
+            (&self.state, GlobalSearchItem::State),
+            (&self.enemy, GlobalSearchItem::Enemy),
+            (&self.term, GlobalSearchItem::Term),
+        ];
+        for (index, item) in terms {
+            items.extend(
+                index
+                    .search(query)
+                    .into_iter()
+                    .take(limit)
+                    .map(|(id, _)| item(id.clone())),
+            );
+        }
+        items
     }
 }
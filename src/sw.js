@@ -0,0 +1,97 @@
+// The service worker of the app. `sw.mjs` writes it into `dist/sw.js` after the build,
+// filling in the hash of the build and the files of the app shell.
+const BUILD = "__BUILD__";
+const PRECACHE = __PRECACHE__;
+
+const SHELL = `shell-${BUILD}`;
+const MANIFESTS = "manifests";
+
+self.addEventListener("install", (event) => {
+    event.waitUntil(
+        caches
+            .open(SHELL)
+            .then((cache) => cache.addAll(PRECACHE))
+            .then(() => self.skipWaiting()),
+    );
+});
+
+self.addEventListener("activate", (event) => {
+    event.waitUntil(
+        deleteCaches((key) => key.startsWith("shell-") && key !== SHELL).then(() =>
+            self.clients.claim(),
+        ),
+    );
+});
+
+// The app sends the files of the database it has loaded. They are cached as
+// `data:<lang>:<version>`, and the previous versions of the language are dropped.
+self.addEventListener("message", (event) => {
+    const { type, lang, version, files } = event.data || {};
+    if (type !== "precache") {
+        return;
+    }
+    const name = `data:${lang}:${version}`;
+    event.waitUntil(
+        caches
+            .open(name)
+            .then((cache) => cache.addAll(files))
+            .then(() => deleteCaches((key) => key.startsWith(`data:${lang}:`) && key !== name)),
+    );
+});
+
+self.addEventListener("fetch", (event) => {
+    const url = new URL(event.request.url);
+    if (event.request.method !== "GET" || url.origin !== self.location.origin) {
+        return;
+    }
+    if (event.request.mode === "navigate") {
+        // Every route is rendered by the app
+        event.respondWith(fetch(event.request).catch(() => caches.match("/index.html")));
+        return;
+    }
+    const manifest = url.pathname.match(/^\/i18n\/([^/]+)\/manifest\.msgpack$/);
+    if (manifest) {
+        event.respondWith(fetchManifest(event, manifest[1]));
+        return;
+    }
+    event.respondWith(
+        caches.match(event.request).then((cached) => cached || fetch(event.request)),
+    );
+});
+
+// Answers with the cached manifest while fetching the current one, and tells the app when
+// the database has changed.
+async function fetchManifest(event, lang) {
+    const cache = await caches.open(MANIFESTS);
+    const cached = await cache.match(event.request);
+    const previous = cached ? await cached.clone().arrayBuffer() : null;
+    const update = fetch(event.request).then(async (response) => {
+        if (!response.ok) {
+            return response;
+        }
+        const current = await response.clone().arrayBuffer();
+        await cache.put(event.request, response.clone());
+        if (previous && !equalBytes(previous, current)) {
+            const clients = await self.clients.matchAll();
+            clients.forEach((client) => client.postMessage({ type: "data-updated", lang }));
+        }
+        return response;
+    });
+    if (cached) {
+        event.waitUntil(update.catch(() => {}));
+        return cached;
+    }
+    return update;
+}
+
+function equalBytes(a, b) {
+    const x = new Uint8Array(a);
+    const y = new Uint8Array(b);
+    return x.length === y.length && x.every((v, i) => v === y[i]);
+}
+
+function deleteCaches(predicate) {
+    return caches
+        .keys()
+        .then((keys) => Promise.all(keys.filter(predicate).map((key) => caches.delete(key))));
+}
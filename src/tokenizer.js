@@ -1,9 +0,0 @@
-export class IntlTokenizer {
-    constructor(locale) {
-        this.segmenter = new Intl.Segmenter(locale, {granularity: "word"});
-    }
-
-    tokenize(text) {
-        return [...this.segmenter.segment(text)[Symbol.iterator]()].map(({segment}) => segment);
-    }
-}
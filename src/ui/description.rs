@@ -1,83 +1,45 @@
 use dioxus::prelude::*;
-use dioxus::web::WebEventExt;
-use wasm_bindgen::JsCast;
+use dioxus::router::router;
+use headlessui::{use_floating, use_id, FloatingOptions, Popover, PopoverButton, PopoverPanel};
 
-use data::token::{Token, Tokens};
+use data::description::{DescriptionNode, DescriptionTree};
+use data::token::{Style, Tokens};
 
 use crate::global::DATABASE;
+use crate::pages::{GlossaryState, Route};
+use crate::ui::Highlight;
 
 #[component]
-pub fn Description(tokens: Tokens, #[props(default = false)] debug: bool) -> Element {
-    let nodes = to_nodes(&tokens);
+pub fn Description(
+    tokens: Tokens,
+    #[props(default = false)] debug: bool,
+    #[props(default)] highlight: Vec<String>,
+) -> Element {
+    let nodes = DescriptionTree::new(&tokens).into_nodes();
 
     rsx! {
         for node in nodes {
-            RenderNode { node, debug }
+            RenderNode { node, debug, highlight: highlight.clone() }
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-enum Node {
-    Text(String),
-    NewLine,
-    Var(String),
-    Error(String),
-    Indent,
-    Term {
-        name: String,
-        tips: Option<String>,
-        children: Vec<Node>,
-    },
-}
-
-fn to_nodes(tokens: &Tokens) -> Vec<Node> {
-    let mut stack = vec![(None, vec![])];
-    for token in tokens.vec() {
-        let node = match token {
-            Token::Text(text) => Some(Node::Text(text.clone())),
-            Token::NewLine => Some(Node::NewLine),
-            Token::Empty => None,
-            Token::Var(name) => Some(Node::Var(name.clone())),
-            Token::Error(text) => Some(Node::Error(text.clone())),
-            Token::Indent => Some(Node::Indent),
-            Token::Panic(text) => Some(Node::Error(text.clone())),
-            n @ Token::TermStart(_, _) => {
-                stack.push((Some(n.clone()), vec![]));
-                None
-            }
-            Token::TermEnd => {
-                let (n, last) = stack.pop().unwrap();
-                if let Token::TermStart(name, tips) = n.unwrap() {
-                    Some(Node::Term {
-                        name: name.clone(),
-                        tips: tips.clone(),
-                        children: last,
-                    })
-                } else {
-                    None
-                }
-            }
-        };
-        if node.is_some() {
-            stack.last_mut().unwrap().1.push(node.unwrap());
-        }
-    }
-    stack.pop().unwrap().1
-}
-
 #[component]
-fn RenderNode(node: Node, #[props(default = false)] debug: bool) -> Element {
+fn RenderNode(
+    node: DescriptionNode,
+    #[props(default = false)] debug: bool,
+    #[props(default)] highlight: Vec<String>,
+) -> Element {
     match node {
-        Node::Text(text) => rsx! {"{text}"},
-        Node::NewLine => rsx! { br {} },
-        Node::Var(name) => rsx! { span { class: "text-error", "[{name}]" } },
-        Node::Error(text) => rsx! { span { class: "text-error font-bold", "{text}" } },
-        Node::Indent => rsx! {
+        DescriptionNode::Text(text) => rsx! { Highlight { text, terms: highlight } },
+        DescriptionNode::NewLine => rsx! { br {} },
+        DescriptionNode::Var(name) => rsx! { span { class: "text-error", "[{name}]" } },
+        DescriptionNode::Error(text) => rsx! { span { class: "text-error font-bold", "{text}" } },
+        DescriptionNode::Indent => rsx! {
             br {}
             "　"
         },
-        Node::Term {
+        DescriptionNode::Term {
             name,
             tips,
             children,
@@ -94,7 +56,7 @@ fn RenderNode(node: Node, #[props(default = false)] debug: bool) -> Element {
                         Tooltip { name: tips,
                             span { class: "text-primary", title: title,
                                 for node in children {
-                                    RenderNode { node, debug }
+                                    RenderNode { node, debug, highlight: highlight.clone() }
                                 }
                             }
                         }
@@ -105,54 +67,98 @@ fn RenderNode(node: Node, #[props(default = false)] debug: bool) -> Element {
                     span { class: "{debug_class} inline-block",
                         span { title: title,
                             for node in children {
-                                RenderNode { node, debug }
+                                RenderNode { node, debug, highlight: highlight.clone() }
                             }
                         }
                     }
                 }
             }
         }
+        DescriptionNode::Styled { style, children } => {
+            let (class, css) = match &style {
+                Style::Bold => ("font-bold", String::new()),
+                Style::Italic => ("italic", String::new()),
+                Style::Underline => ("underline", String::new()),
+                Style::Strikethrough => ("line-through", String::new()),
+                Style::Color(color) => ("", text_color(color)),
+                Style::Size(_) => ("", String::new()),
+            };
+            rsx! {
+                span { class, style: css,
+                    for node in children {
+                        RenderNode { node, debug, highlight: highlight.clone() }
+                    }
+                }
+            }
+        }
     }
 }
 
+/// The style of a game color. Colors of a rarity follow the rarity colors of the game data.
+fn text_color(color: &str) -> String {
+    let database = DATABASE.read();
+    let color = match database.global.rarity_of_color(color) {
+        Some(rarity) => format!("#{}", database.global.rarity_colors[rarity as usize - 1]),
+        None => color.to_string(),
+    };
+    // The game colors are picked for a dark background, so blend in the text color
+    // of the theme to keep them readable on light themes
+    format!(
+        "color: color-mix(in oklab, {} 70%, oklch(var(--bc)));",
+        color
+    )
+}
+
+/// The name and description of the term `name`, opened by hovering or tapping `children`.
+/// Terms inside the description open their own tooltips, and the title links to the
+/// glossary.
 #[component]
 pub fn Tooltip(name: String, #[props(default = false)] debug: bool, children: Element) -> Element {
     let title = DATABASE().term.get(&format!("NM-{}", name));
     let body = DATABASE().term.get(&format!("DC-{}", name));
+    let glossary = router()
+        .current::<Route>()
+        .language()
+        .filter(|_| DATABASE().in_glossary(&name))
+        .map(|language| Route::GlossaryPage {
+            language,
+            state: GlossaryState::term(&name),
+        });
 
-    let mut popover_offset = use_signal(|| None::<f64>);
-    let popover_style = use_memo(move || {
-        if let Some(offset) = popover_offset() {
-            format!("right: {}px;", offset)
-        } else {
-            String::default()
-        }
-    });
+    let id = use_id("tooltip");
+    let reference_id = format!("{}-reference", id);
+    let floating_id = format!("{}-floating", id);
+    let floating = use_floating(
+        reference_id.clone(),
+        floating_id.clone(),
+        FloatingOptions::default(),
+    );
+    let floating2 = floating.clone();
 
     rsx! {
-        div { class: "dropdown dropdown-end",
-            div { tabindex: 0, role: "button", {children} }
-            div {
-                class: "dropdown-content z-[1] card card-compact card-bordered border-base-300 shadow-lg shadow-black/50 bg-base-100 text-base-content min-w-64 max-w-full",
-                tabindex: 0,
-                style: "{popover_style}",
-                onmounted: move |e| {
-                    async move {
-                        let r = e.data.get_client_rect().await;
-                        let x = r.unwrap().origin.x;
-                        let el = e.web_event().dyn_ref::<web_sys::HtmlElement>().unwrap();
-                        let dropdown = el.offset_parent().unwrap();
-                        let dropdown = dropdown.dyn_ref::<web_sys::HtmlElement>().unwrap();
-                        let parent = dropdown.offset_parent().unwrap();
-                        let offset_x = parent.get_bounding_client_rect().x();
-                        if x < offset_x {
-                            *popover_offset.write() = Some(x - offset_x - 16.0);
+        Popover { class: "inline-block", hover: true,
+            PopoverButton { class: "inline text-left",
+                span { id: reference_id, {children} }
+            }
+            PopoverPanel { class: "contents",
+                div {
+                    id: floating_id,
+                    class: "z-50 card card-compact card-bordered border-base-300 shadow-lg shadow-black/50 bg-base-100 text-base-content min-w-64 w-max max-w-[min(24rem,calc(100vw-16px))]",
+                    style: floating.style(),
+                    onmounted: move |_| floating2.update(),
+                    div {
+                        class: "absolute w-3 h-3 rotate-45 bg-base-100",
+                        style: floating.arrow_style(12.0)
+                    }
+                    div { class: "card-body",
+                        div { class: "flex flex-row items-baseline gap-2",
+                            span { class: "font-bold flex-grow", Description { tokens: title, debug } }
+                            if let Some(glossary) = glossary {
+                                Link { class: "link link-primary text-xs", to: glossary, "Glossary" }
+                            }
                         }
+                        Description { tokens: body, debug }
                     }
-                },
-                div { class: "card-body",
-                    span { class: "font-bold", Description { tokens: title, debug } }
-                    Description { tokens: body, debug }
                 }
             }
         }
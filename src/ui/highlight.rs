@@ -0,0 +1,31 @@
+use dioxus::prelude::*;
+
+use data::tokenizer::highlight;
+
+/// Renders `text`, marking the parts which match the search `terms`.
+#[component]
+pub fn Highlight(text: String, terms: Vec<String>) -> Element {
+    let ranges = highlight(&text, &terms);
+    if ranges.is_empty() {
+        return rsx! {"{text}"};
+    }
+
+    let mut parts = vec![];
+    let mut last = 0;
+    for range in ranges {
+        parts.push((text[last..range.start].to_string(), false));
+        parts.push((text[range.clone()].to_string(), true));
+        last = range.end;
+    }
+    parts.push((text[last..].to_string(), false));
+
+    rsx! {
+        for (part, marked) in parts {
+            if marked {
+                mark { class: "bg-warning text-warning-content rounded-sm", "{part}" }
+            } else {
+                "{part}"
+            }
+        }
+    }
+}
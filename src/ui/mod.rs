@@ -1,11 +1,13 @@
 pub use self::description::*;
 pub use self::dialog::*;
+pub use self::highlight::*;
 pub use self::icon::*;
 pub use self::rarity::*;
 pub use self::sprite::*;
 
 mod description;
 mod dialog;
+mod highlight;
 mod icon;
 mod rarity;
 mod sprite;
@@ -1,10 +1,27 @@
 use dioxus::prelude::*;
 
+/// The resolutions of the atlases written by `gen`.
+const RESOLUTIONS: [f64; 3] = [1.0, 2.0, 4.0];
+
+/// The lowest resolution which covers the device pixels of a sprite drawn at `scale`.
+fn resolution(scale: f64) -> i32 {
+    let ratio = web_sys::window()
+        .map(|w| w.device_pixel_ratio())
+        .unwrap_or(1.0);
+    let pixels = scale * ratio;
+    RESOLUTIONS
+        .iter()
+        .find(|r| **r >= pixels)
+        .copied()
+        .unwrap_or(RESOLUTIONS[RESOLUTIONS.len() - 1]) as i32
+}
+
 #[component]
 pub fn Sprite(sprite: Signal<data::Sprite>, scale: f64) -> Element {
+    let resolution = resolution(scale);
     rsx! {
         span {
-            class: "inline-block sprite sprite-{sprite().index}",
+            class: "inline-block sprite sprite-{sprite().index} sprite-{resolution}x",
             style: "--x: {sprite().x}px; --y: {sprite().y}px; --w: {sprite().width}px; --h: {sprite().height}px; --s: {scale}"
         }
     }
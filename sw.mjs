@@ -0,0 +1,32 @@
+import {createHash} from 'node:crypto';
+import {readdirSync, readFileSync, writeFileSync} from 'node:fs';
+import {join, relative, sep} from 'node:path';
+
+// Writes the service worker into the build, precaching the app shell and the sprite
+// atlases. The databases are cached by the app once a language is loaded.
+const dist = 'dist';
+
+function files(dir) {
+    return readdirSync(dir, {withFileTypes: true}).flatMap((entry) => {
+        const path = join(dir, entry.name);
+        return entry.isDirectory() ? files(path) : [path];
+    });
+}
+
+const precache = files(dist)
+    .map((path) => '/' + relative(dist, path).split(sep).join('/'))
+    .filter((path) => !path.startsWith('/i18n/') && path !== '/sw.js')
+    .sort();
+
+const hash = createHash('sha256');
+for (const path of precache) {
+    hash.update(path);
+    hash.update(readFileSync(join(dist, path)));
+}
+const build = hash.digest('hex').slice(0, 8);
+
+const source = readFileSync('src/sw.js', 'utf8')
+    .replace('__BUILD__', build)
+    .replace('__PRECACHE__', JSON.stringify(precache, null, 4));
+writeFileSync(join(dist, 'sw.js'), source);
+console.log(`sw.js: build ${build}, ${precache.length} files`);